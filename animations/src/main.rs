@@ -1,6 +1,8 @@
 use eframe::egui;
 use eframe::epaint::{Color32, Pos2, Rect, Vec2};
+use std::collections::VecDeque;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 fn main() {
     start_puffin_server();
@@ -8,20 +10,26 @@ fn main() {
     eframe::run_native(
         "Animation Widget",
         options,
-        Box::new(|cc| Ok(Box::new(AnimationApp::new(cc)))),
+        Box::new(|cc| {
+            Ok(Box::new(
+                AnimationApp::new(cc).with_puffin_frame_cadence(1, Duration::from_millis(16)),
+            ))
+        }),
     )
     .expect("Failed to run egui application");
 }
 
 pub fn set_native_options() -> eframe::NativeOptions {
-    let mut options = eframe::NativeOptions::default();
-    options.centered = true;
-    options.vsync = true;
-    options.renderer = eframe::Renderer::Wgpu;
-    options
+    eframe::NativeOptions {
+        centered: true,
+        vsync: true,
+        renderer: eframe::Renderer::Wgpu,
+        ..Default::default()
+    }
 }
 
 #[derive(Default)]
+#[allow(clippy::upper_case_acronyms)]
 enum Editing {
     #[default]
     VERSION,
@@ -35,7 +43,17 @@ struct RowData {
     path: String,
     galley_version: Option<Arc<egui::Galley>>,
     galley_path: Option<Arc<egui::Galley>>,
+    // Extra detail such as size on disk, shown in the metadata column
+    meta: Option<String>,
+    galley_meta: Option<Arc<egui::Galley>>,
+    // Short status such as "latest", pinned to the right edge of the row
+    badge: Option<String>,
+    galley_badge: Option<Arc<egui::Galley>>,
     editing: Editing,
+    selected: bool,
+    disabled: bool,
+    // Shown as a tooltip while the row is disabled
+    disabled_reason: Option<String>,
 }
 
 impl RowData {
@@ -45,42 +63,565 @@ impl RowData {
             path,
             galley_version: None,
             galley_path: None,
+            meta: None,
+            galley_meta: None,
+            badge: None,
+            galley_badge: None,
             editing: Editing::NONE,
+            selected: false,
+            disabled: false,
+            disabled_reason: None,
+        }
+    }
+
+    // Rough size of the cached galleys: their glyphs plus the meshes built for them
+    fn galley_memory(&self) -> usize {
+        [
+            &self.galley_version,
+            &self.galley_path,
+            &self.galley_meta,
+            &self.galley_badge,
+        ]
+        .into_iter()
+        .flatten()
+        .flat_map(|galley| &galley.rows)
+        .map(|row| {
+            row.glyphs.len() * std::mem::size_of::<egui::epaint::text::Glyph>()
+                + row.visuals.mesh.vertices.len() * std::mem::size_of::<egui::epaint::Vertex>()
+                + row.visuals.mesh.indices.len() * std::mem::size_of::<u32>()
+        })
+        .sum()
+    }
+
+    // Case-insensitive match on the version or path, `filter` is already lowercase
+    fn matches(&self, filter: &str) -> bool {
+        filter.is_empty()
+            || self.version.to_lowercase().contains(filter)
+            || self.path.to_lowercase().contains(filter)
+    }
+
+    #[allow(dead_code)]
+    fn with_meta(mut self, meta: impl Into<String>) -> Self {
+        self.meta = Some(meta.into());
+        self
+    }
+
+    #[allow(dead_code)]
+    fn with_badge(mut self, badge: impl Into<String>) -> Self {
+        self.badge = Some(badge.into());
+        self
+    }
+
+    #[allow(dead_code)]
+    fn disabled(mut self, reason: Option<String>) -> Self {
+        self.disabled = true;
+        self.disabled_reason = reason;
+        self
+    }
+}
+
+// Version strings compare by their text and numeric runs, so "GE-Proton9-10" sorts after "GE-Proton9-9"
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum VersionPart {
+    Text(String),
+    Number(u64),
+}
+
+fn parse_version(version: &str) -> Vec<VersionPart> {
+    let mut parts = Vec::new();
+    let mut chars = version.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        let is_digit = c.is_ascii_digit();
+        let mut run = String::new();
+        while let Some(&c) = chars.peek() {
+            if c.is_ascii_digit() != is_digit {
+                break;
+            }
+            run.push(c);
+            chars.next();
+        }
+        parts.push(if is_digit {
+            VersionPart::Number(run.parse().unwrap_or(u64::MAX))
+        } else {
+            VersionPart::Text(run)
+        });
+    }
+    parts
+}
+
+trait Lerp: Copy {
+    fn lerp(self, target: Self, t: f32) -> Self;
+}
+
+impl Lerp for f32 {
+    fn lerp(self, target: Self, t: f32) -> Self {
+        self + (target - self) * t
+    }
+}
+
+impl Lerp for Color32 {
+    fn lerp(self, target: Self, t: f32) -> Self {
+        self.lerp_to_gamma(target, t)
+    }
+}
+
+// Animates a single value from wherever it currently is to a new target
+struct PropertyAnimation<T> {
+    start: T,
+    target: T,
+    start_time: f64,
+    duration: f32,
+    easing: fn(f32) -> f32,
+}
+
+impl<T: Default + Lerp> Default for PropertyAnimation<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Lerp> PropertyAnimation<T> {
+    fn new(value: T) -> Self {
+        Self {
+            start: value,
+            target: value,
+            start_time: 0.0,
+            duration: 0.0,
+            easing: egui::emath::easing::quadratic_out,
+        }
+    }
+
+    #[allow(dead_code)]
+    fn with_easing(mut self, easing: fn(f32) -> f32) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    fn animate_to(&mut self, target: T, time: f64, duration: f32) {
+        self.start = self.value(time);
+        self.target = target;
+        self.start_time = time;
+        self.duration = duration;
+    }
+
+    fn is_animating(&self, time: f64) -> bool {
+        time < self.start_time + self.duration as f64
+    }
+
+    #[inline]
+    fn value(&self, time: f64) -> T {
+        if !self.is_animating(time) {
+            return self.target;
+        }
+        let t = ((time - self.start_time) as f32 / self.duration).clamp(0.0, 1.0);
+        self.start.lerp(self.target, (self.easing)(t))
+    }
+}
+
+const DEFAULT_EASING_RESOLUTION: usize = 64;
+
+type EasingFn = Arc<dyn Fn(f32) -> f32>;
+
+// Easing curve sampled into a lookup table, so costly curves (e.g. splines) are only evaluated up front
+#[derive(Clone)]
+struct Easing {
+    curve: EasingFn,
+    samples: Vec<f32>,
+}
+
+impl Default for Easing {
+    fn default() -> Self {
+        Self::new(
+            egui::emath::easing::quadratic_out,
+            DEFAULT_EASING_RESOLUTION,
+        )
+    }
+}
+
+impl Easing {
+    fn new(curve: impl Fn(f32) -> f32 + 'static, resolution: usize) -> Self {
+        Self::from_curve(Arc::new(curve), resolution)
+    }
+
+    fn from_curve(curve: EasingFn, resolution: usize) -> Self {
+        let last = resolution.max(2) - 1;
+        let samples = (0..=last).map(|i| curve(i as f32 / last as f32)).collect();
+        Self { curve, samples }
+    }
+
+    #[allow(dead_code)]
+    fn with_resolution(&self, resolution: usize) -> Self {
+        Self::from_curve(self.curve.clone(), resolution)
+    }
+
+    fn resolution(&self) -> usize {
+        self.samples.len()
+    }
+
+    // Evaluates the curve itself rather than the lookup table
+    #[allow(dead_code)]
+    fn exact(&self, t: f32) -> f32 {
+        (self.curve)(t)
+    }
+
+    #[inline]
+    fn sample(&self, t: f32) -> f32 {
+        let last = self.samples.len() - 1;
+        let position = t.clamp(0.0, 1.0) * last as f32;
+        let i = (position as usize).min(last - 1);
+        let frac = position - i as f32;
+        self.samples[i] + (self.samples[i + 1] - self.samples[i]) * frac
+    }
+}
+
+// CSS-style cubic-bezier easing, with the end points fixed at (0, 0) and (1, 1)
+#[derive(Clone, Copy)]
+struct CubicBezier {
+    p1: Vec2,
+    p2: Vec2,
+}
+
+impl Default for CubicBezier {
+    // The quadratic_out curve raised to a cubic
+    fn default() -> Self {
+        Self {
+            p1: Vec2::new(1.0 / 3.0, 2.0 / 3.0),
+            p2: Vec2::new(2.0 / 3.0, 1.0),
+        }
+    }
+}
+
+impl CubicBezier {
+    fn point(&self, t: f32) -> Vec2 {
+        let u = 1.0 - t;
+        self.p1 * (3.0 * u * u * t) + self.p2 * (3.0 * u * t * t) + Vec2::splat(t * t * t)
+    }
+
+    // Finds the curve parameter for `x` by bisection, which is fine since the easing is sampled up front
+    fn ease(&self, x: f32) -> f32 {
+        let (mut low, mut high) = (0.0, 1.0);
+        for _ in 0..24 {
+            let mid = (low + high) / 2.0;
+            if self.point(mid).x < x {
+                low = mid;
+            } else {
+                high = mid;
+            }
         }
+        self.point((low + high) / 2.0).y
     }
 }
 
+// How the version text behaves when it is wider than its cell
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+enum VersionOverflow {
+    #[default]
+    Truncate,
+    Ellipsis,
+    MarqueeOnHover,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum SortMode {
+    Version,
+    // Most recently clicked rows first
+    RecentlyViewed,
+}
+
+// How long a row takes to grow back when a filter stops hiding it
+const REVEAL_DURATION: f32 = 0.25;
+
+// How long rows take to slide to their new place after a sort
+const SORT_DURATION: f32 = 0.3;
+
+// What clicking a row's version cell does
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+#[allow(dead_code)]
+enum ClickBehavior {
+    Select,
+    #[default]
+    Edit,
+    // Leaves the click to the caller through `clicked_row`
+    Custom,
+}
+
+// How a looping entrance starts its next cycle
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[allow(dead_code)]
+enum LoopMode {
+    // Jump back to the start and slide in again
+    Restart,
+    // Alternate between sliding in and sliding back out
+    PingPong,
+}
+
+// How removed rows leave the list
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+enum RemovalStyle {
+    // Run the entrance backwards
+    #[default]
+    SlideOut,
+    // Fade out in place while the rows below close up
+    Collapse,
+}
+
+const REMOVAL_DURATION: f32 = 0.25;
+
+// A shake swings the row this many times over its duration, at most this far to either side
+const SHAKE_DURATION: f32 = 0.3;
+const SHAKE_CYCLES: f32 = 3.0;
+const SHAKE_AMPLITUDE: f32 = 8.0;
+
+// Marquee scroll speed in points per second, and the gap before the text repeats
+const MARQUEE_SPEED: f32 = 40.0;
+const MARQUEE_GAP: f32 = 40.0;
+
 #[derive(Default)]
 struct AnimatedRow {
     data: RowData,
     start_time: f64,
     animation_time: f32,
     delay: f32,
+    height: PropertyAnimation<f32>,
+    fill: PropertyAnimation<Color32>,
+    // When the pointer started hovering an overflowing version
+    marquee_start: Option<f64>,
+    // Filtered out of the list
+    hidden: bool,
+    // Just un-hidden by the filter, animated back in on the next show
+    revealing: bool,
+    // Waiting for a free entrance slot when concurrent entrances are capped
+    queued: bool,
+    // Time the row was last clicked, for SortMode::RecentlyViewed
+    last_viewed: f64,
+    // Vertical distance from the row's position before the last sort
+    sort_offset: PropertyAnimation<f32>,
+    // Pass the row's galleys were last drawn in, so the least recently used are evicted first
+    galley_used: u64,
+    // When the row started leaving the list, it is dropped once the removal finishes
+    exiting: Option<f64>,
+    shake_start: Option<f64>,
 }
 
 impl AnimatedRow {
-    fn new(row_data: RowData, start_time: f64, duration: f32, delay: f32) -> Self {
+    fn new(row_data: RowData, start_time: f64, duration: f32, delay: f32, height: f32) -> Self {
         Self {
             data: row_data,
             start_time,
             animation_time: duration,
             delay,
+            height: PropertyAnimation::new(height),
+            fill: PropertyAnimation::new(Color32::WHITE),
+            marquee_start: None,
+            hidden: false,
+            revealing: false,
+            queued: false,
+            last_viewed: f64::NEG_INFINITY,
+            sort_offset: PropertyAnimation::new(0.0),
+            galley_used: 0,
+            exiting: None,
+            shake_start: None,
+        }
+    }
+
+    // Moves every animation on this row forward in time, e.g. to skip over a pause
+    fn shift_start_time(&mut self, offset: f64) {
+        self.start_time += offset;
+        self.height.start_time += offset;
+        self.sort_offset.start_time += offset;
+        self.fill.start_time += offset;
+        for start in [
+            &mut self.exiting,
+            &mut self.shake_start,
+            &mut self.marquee_start,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            *start += offset;
         }
     }
 
+    // Damped horizontal wobble, back at zero once the shake is over
+    fn shake_offset(&mut self, time: f64) -> f32 {
+        let Some(start) = self.shake_start else {
+            return 0.0;
+        };
+        let t = (time - start) as f32 / SHAKE_DURATION;
+        if t >= 1.0 {
+            self.shake_start = None;
+            return 0.0;
+        }
+        SHAKE_AMPLITUDE * (1.0 - t) * (t * SHAKE_CYCLES * std::f32::consts::TAU).sin()
+    }
+
+    fn is_entering(&self, time: f64) -> bool {
+        !self.queued && time < self.start_time + (self.delay + self.animation_time) as f64
+    }
+
     // Simplified animation progress calculation
     #[inline]
-    fn get_progress(&self, time: f64) -> f32 {
+    fn get_progress(&self, time: f64, easing: &Easing, looping: Option<LoopMode>) -> f32 {
+        if self.queued {
+            return easing.sample(0.0);
+        }
         let elapsed = (time - self.start_time - self.delay as f64).max(0.0) as f32;
-        let t = (elapsed / self.animation_time).min(1.0);
-        egui::emath::easing::quadratic_out(t)
+        let cycles = elapsed / self.animation_time;
+        let t = match looping {
+            None => cycles.min(1.0),
+            Some(LoopMode::Restart) => cycles.fract(),
+            // Every other cycle plays backwards, so the row goes in and back out
+            Some(LoopMode::PingPong) if cycles as u32 % 2 == 1 => 1.0 - cycles.fract(),
+            Some(LoopMode::PingPong) => cycles.fract(),
+        };
+        easing.sample(t)
     }
 }
 
+// Longest the whole staggered entrance may take before the stagger is squeezed to fit
+const MAX_ENTRANCE_TIME: f32 = 10.0;
+
+// How long a snapping drag takes to move between slots
+const SNAP_DURATION: f32 = 0.1;
+
+// A row being dragged to a new position
+struct RowDrag {
+    index: usize,
+    // Position in the on-screen order the row will be dropped at
+    slot: usize,
+    grab_offset: f32,
+    // Top of the dragged row relative to the list, animated between slots when snapping
+    slot_top: PropertyAnimation<f32>,
+}
+
+// Rows stay compact this long after the last scroll event, then take this long to grow back
+const COMPACT_HOLD: f32 = 0.2;
+const COMPACT_DURATION: f32 = 0.15;
+
+// Seconds a scroll_to takes to bring its row to the top of the view
+const SCROLL_DURATION: f32 = 0.3;
+
+struct ScrollTo {
+    // Scroll offsets relative to the top of the list
+    from: f32,
+    to: f32,
+    start_time: f64,
+    // Where the deltas so far have taken the view, the layout only catches up a frame later
+    requested: f32,
+}
+
+// Rows only take clicks once they are this far into their entrance
+const INTERACTIVE_PROGRESS: f32 = 0.9;
+
+type UiHook = Box<dyn Fn(&mut egui::Ui)>;
+
 #[derive(Default)]
 struct AnimatedRowList {
     rows: Vec<AnimatedRow>,
     row_height: f32,
+    animation_duration: f32,
+    // The requested stagger was squeezed to keep the entrance within MAX_ENTRANCE_TIME
+    stagger_clamped: bool,
+    // Base time of a timeline shared with other lists, and when this list enters on it
+    start_time: f64,
+    start_offset: f32,
+    // Rows pushed while streaming, admitted at most `append_limit` per show
+    pending_rows: VecDeque<RowData>,
+    append_limit: Option<usize>,
+    // Rendered in place of the rows when the list has nothing to show
+    on_empty: Option<UiHook>,
+    // Skip the entrance on every show after the first one that finished
+    play_once: bool,
+    played: bool,
+    // Keeps this list's state in egui memory apart from other lists in the same Ui
+    id_salt: Option<egui::Id>,
+    // Anti-aliased row edges; turn off for crisp pixel-aligned rects
+    feathering: bool,
+    // How long the last show took to lay out and paint
+    last_frame_cost: Duration,
+    interactive_while_animating: bool,
+    // Screen-space top of the list in the last frame, used to map scroll offsets to rows
+    list_top: f32,
+    // Freeze the clock while the window is unfocused
+    pause_on_focus_loss: bool,
+    paused_at: Option<f64>,
+    // Border around each cell, faded in with the row
+    row_stroke: Option<egui::Stroke>,
+    // Row 0 sits at the bottom and later rows stack above it, sliding down from the top
+    grow_upward: bool,
+    easing: Easing,
+    version_overflow: VersionOverflow,
+    // Vertical gap between rows, taken from the Ui style each show
+    row_spacing: f32,
+    // Case-insensitive match on the version or path; empty shows every row
+    filter: String,
+    // Typed filters wait this long for the next keystroke before the rows are filtered
+    filter_debounce: f32,
+    pending_filter: Option<(String, f64)>,
+    // Times the rows were matched against a filter, to check the debounce is doing its job
+    filter_recomputes: usize,
+    // Cell fills while hovered and while the pointer is held down on them
+    hover_fill: Option<Color32>,
+    pressed_fill: Option<Color32>,
+    click_behavior: ClickBehavior,
+    selection_fill: Color32,
+    // Seconds the selection fill takes to fade in and out
+    selection_fade: f32,
+    // Never let the selection become empty, picking the first enabled row if nothing is selected
+    require_selection: bool,
+    // Row whose version cell was clicked this frame
+    clicked_row: Option<usize>,
+    // Start each entrance once a running one finishes, instead of on a fixed stagger
+    max_concurrent_entrances: Option<usize>,
+    // Limits how many rows are shown, independently of the clock
+    reveal_fraction: Option<f32>,
+    removal_style: RemovalStyle,
+    // Replay the entrance forever, e.g. to draw attention to the list
+    looping: Option<LoopMode>,
+    // Re-applied whenever a click changes what it sorts by
+    sort_mode: Option<SortMode>,
+    // Estimated bytes of cached galleys to keep before dropping the least recently drawn
+    galley_memory_budget: Option<usize>,
+    // Row moved between with the arrow keys, outlined rather than filled so it shows over a selection
+    focused: Option<usize>,
+    focus_stroke: egui::Stroke,
+    scroll_to_focused: bool,
+    // Smooth scroll_to requests, eased separately from the row entrances
+    scroll_easing: Easing,
+    scroll_target: Option<usize>,
+    scroll: Option<ScrollTo>,
+    // Jump back to the top of the list whenever the filter changes
+    reset_scroll_on_filter: bool,
+    reset_scroll: bool,
+    // Hovering a row focuses it, unless the arrow keys moved the focus since the pointer last moved
+    focus_follows_hover: bool,
+    delete_keys: bool,
+    // Hovering a row this long selects it alone, leaving it first cancels the selection
+    hover_select_delay: Option<f32>,
+    // Row under the pointer, when it was first hovered and whether it was selected since
+    hover_start: Option<(usize, f64, bool)>,
+    keyboard_focus: bool,
+    // Panel drawn behind each row's cells; clicking it focuses the row unless clicks pass through
+    row_background: Option<Color32>,
+    click_through_background: bool,
+    // Family to lay out versions and paths with when the default font lacks some of their glyphs
+    fallback_font: Option<egui::FontFamily>,
+    // Row height multiplier while the user scrolls over the list
+    compact_when_scrolling: Option<f32>,
+    last_scroll_time: f64,
+    height_scale: f32,
+    // Width below which rows switch to the stacked, two-line layout
+    stack_below: Option<f32>,
+    // One interaction per row instead of one per cell, for long lists
+    single_interaction: bool,
+    // Fraction of the row width given to a third, metadata column on the right
+    meta_column: Option<f32>,
+    // Drag rows to reorder them, optionally jumping between slots instead of following the pointer
+    reorderable: bool,
+    snap_reorder: bool,
+    drag: Option<RowDrag>,
+    // (row index, top relative to the list, height) of each visible row, in on-screen order
+    slots: Vec<(usize, f32, f32)>,
 }
 
 impl AnimatedRowList {
@@ -90,6 +631,15 @@ impl AnimatedRowList {
         animation_duration: f32,
         stagger_delay: f32,
     ) -> Self {
+        let row_height = 60.0;
+        let last_row = rows.len().saturating_sub(1) as f32;
+        let stagger_clamped =
+            last_row > 0.0 && animation_duration + last_row * stagger_delay > MAX_ENTRANCE_TIME;
+        let stagger_delay = if stagger_clamped {
+            (MAX_ENTRANCE_TIME - animation_duration).max(0.0) / last_row
+        } else {
+            stagger_delay
+        };
         let animated_rows = rows
             .into_iter()
             .enumerate()
@@ -99,173 +649,3203 @@ impl AnimatedRowList {
                     start_time,
                     animation_duration,
                     i as f32 * stagger_delay,
+                    row_height,
                 )
             })
             .collect();
         Self {
             rows: animated_rows,
-            row_height: 60.0,
+            row_height,
+            animation_duration,
+            stagger_clamped,
+            start_time,
+            start_offset: 0.0,
+            pending_rows: VecDeque::new(),
+            append_limit: None,
+            on_empty: None,
+            play_once: false,
+            played: false,
+            id_salt: None,
+            feathering: true,
+            last_frame_cost: Duration::ZERO,
+            interactive_while_animating: false,
+            list_top: 0.0,
+            pause_on_focus_loss: false,
+            paused_at: None,
+            row_stroke: None,
+            grow_upward: false,
+            easing: Easing::default(),
+            version_overflow: VersionOverflow::default(),
+            row_spacing: egui::Spacing::default().item_spacing.y,
+            filter: String::new(),
+            filter_debounce: 0.0,
+            pending_filter: None,
+            filter_recomputes: 0,
+            hover_fill: None,
+            pressed_fill: None,
+            click_behavior: ClickBehavior::default(),
+            selection_fill: Color32::from_rgb(144, 209, 255),
+            selection_fade: 0.15,
+            require_selection: false,
+            clicked_row: None,
+            max_concurrent_entrances: None,
+            reveal_fraction: None,
+            removal_style: RemovalStyle::default(),
+            looping: None,
+            sort_mode: None,
+            galley_memory_budget: None,
+            focused: None,
+            focus_stroke: egui::Stroke::new(2.0, Color32::from_rgb(0, 92, 128)),
+            scroll_to_focused: false,
+            scroll_easing: Easing::default(),
+            scroll_target: None,
+            scroll: None,
+            reset_scroll_on_filter: true,
+            reset_scroll: false,
+            focus_follows_hover: false,
+            delete_keys: false,
+            hover_select_delay: None,
+            hover_start: None,
+            keyboard_focus: false,
+            row_background: None,
+            click_through_background: false,
+            fallback_font: None,
+            stack_below: None,
+            single_interaction: false,
+            meta_column: None,
+            compact_when_scrolling: None,
+            last_scroll_time: f64::NEG_INFINITY,
+            height_scale: 1.0,
+            reorderable: false,
+            snap_reorder: false,
+            drag: None,
+            slots: Vec::new(),
         }
     }
 
-    pub fn show(&mut self, ui: &mut egui::Ui) {
-        let time = ui.input(|i| i.time);
-        let mut needs_redraw = false;
+    pub fn with_on_empty(mut self, on_empty: impl Fn(&mut egui::Ui) + 'static) -> Self {
+        self.on_empty = Some(Box::new(on_empty));
+        self
+    }
 
-        ui.vertical(|ui| {
-            for row in &mut self.rows {
-                ui.horizontal(|ui| {
-                    let progress = row.get_progress(time);
-                    needs_redraw |= progress < 1.0;
+    #[allow(dead_code)]
+    pub fn with_play_once(mut self, play_once: bool) -> Self {
+        self.play_once = play_once;
+        self
+    }
 
-                    let (_id, full_rect) =
-                        ui.allocate_space(Vec2::new(ui.available_width(), self.row_height));
+    #[allow(dead_code)]
+    pub fn with_id_salt(mut self, salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(egui::Id::new(salt));
+        self
+    }
 
-                    let half_width = full_rect.width() / 2.0;
+    #[allow(dead_code)]
+    pub fn with_feathering(mut self, feathering: bool) -> Self {
+        self.feathering = feathering;
+        self
+    }
 
-                    let start_x = full_rect.left() + half_width;
-                    let target_x = full_rect.left();
-                    let x_offset = start_x + (target_x - start_x) * progress;
+    #[allow(dead_code)]
+    pub fn sort_by_version(&mut self) {
+        let order = self.sort_order(SortMode::Version);
+        self.apply_order(&order);
+    }
 
-                    let start_x2 = full_rect.right();
-                    let target_x2 = start_x;
-                    let x_offset2 = start_x2 + (target_x2 - start_x2) * progress;
+    #[allow(dead_code)]
+    pub fn with_sort_mode(mut self, mode: SortMode) -> Self {
+        self.sort_mode = Some(mode);
+        let order = self.sort_order(mode);
+        self.apply_order(&order);
+        self
+    }
 
-                    let animated_rect = Rect::from_min_size(
-                        Pos2::new(x_offset, full_rect.top()),
-                        Vec2::new(half_width, full_rect.height()),
-                    );
+    // Sorts the rows and slides each one from where it was to its new place
+    pub fn sort(&mut self, mode: SortMode, time: f64) {
+        let old_tops = self.row_tops(time);
+        let order = self.sort_order(mode);
+        self.apply_order(&order);
+        let new_tops = self.row_tops(time);
 
-                    let response = ui.interact(
-                        animated_rect,
-                        ui.next_auto_id().with(&row.data.version),
-                        egui::Sense::click(),
-                    );
+        for (i, row) in self.rows.iter_mut().enumerate() {
+            let old_top = old_tops[order[i]] + row.sort_offset.value(time);
+            row.sort_offset = PropertyAnimation::new(old_top - new_tops[i]);
+            row.sort_offset.animate_to(0.0, time, SORT_DURATION);
+        }
+    }
 
-                    let animated_rect2 = Rect::from_min_size(
-                        Pos2::new(x_offset2, full_rect.top()),
-                        Vec2::new(half_width, full_rect.height()),
-                    );
+    // Indices into `rows` in sorted order
+    fn sort_order(&self, mode: SortMode) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.rows.len()).collect();
+        match mode {
+            // Rows with the same parsed version fall back to their path, so the order is always the same
+            SortMode::Version => order.sort_by_cached_key(|&i| {
+                let data = &self.rows[i].data;
+                (parse_version(&data.version), data.path.clone())
+            }),
+            SortMode::RecentlyViewed => order.sort_by(|&a, &b| {
+                self.rows[b]
+                    .last_viewed
+                    .total_cmp(&self.rows[a].last_viewed)
+            }),
+        }
+        order
+    }
 
-                    let response2 = ui.interact(
-                        animated_rect2,
-                        ui.next_auto_id().with(&row.data.path),
-                        egui::Sense::click(),
-                    );
+    fn apply_order(&mut self, order: &[usize]) {
+        let mut rows: Vec<Option<AnimatedRow>> = self.rows.drain(..).map(Some).collect();
+        self.rows = order.iter().filter_map(|&i| rows[i].take()).collect();
+        // Keep the focus on the same row rather than the same index
+        self.focused = self
+            .focused
+            .and_then(|focused| order.iter().position(|&i| i == focused));
+    }
 
-                    let alpha = (255.0 * progress) as u8;
+    // Top of every row relative to the list, indexed like `rows`
+    fn row_tops(&self, time: f64) -> Vec<f32> {
+        let mut tops = vec![0.0; self.rows.len()];
+        let mut top = 0.0;
+        for i in 0..self.rows.len() {
+            let index = self.layout_index(i);
+            tops[index] = top;
+            if !self.rows[index].hidden {
+                top += self.rows[index].height.value(time) * self.height_scale + self.row_spacing;
+            }
+        }
+        tops
+    }
 
-                    // Direct painting to avoid allocations
-                    ui.painter().rect_filled(
-                        animated_rect,
-                        0.0, // Keep your corner radius
-                        Color32::from_rgba_unmultiplied(255, 255, 255, alpha),
-                    );
+    #[allow(dead_code)]
+    pub fn with_interactive_while_animating(mut self, interactive: bool) -> Self {
+        self.interactive_while_animating = interactive;
+        self
+    }
 
-                    ui.painter().rect_filled(
-                        animated_rect2,
-                        0.0,
-                        Color32::from_rgba_unmultiplied(180, 180, 180, alpha),
-                    );
+    #[allow(dead_code)]
+    pub fn with_pause_on_focus_loss(mut self, pause: bool) -> Self {
+        self.pause_on_focus_loss = pause;
+        self
+    }
 
-                    // Cache and reuse text galley
-                    let galley = row.data.galley_version.get_or_insert_with(|| {
-                        ui.painter().layout_no_wrap(
-                            row.data.version.clone(),
-                            egui::FontId::new(20.0, egui::FontFamily::Proportional),
-                            Color32::BLACK,
-                        )
-                    });
+    #[allow(dead_code)]
+    pub fn with_row_stroke(mut self, stroke: egui::Stroke) -> Self {
+        self.row_stroke = Some(stroke);
+        self
+    }
 
-                    let galley2 = row.data.galley_path.get_or_insert_with(|| {
-                        ui.painter().layout_no_wrap(
-                            row.data.path.clone(),
-                            egui::FontId::new(20.0, egui::FontFamily::Proportional),
-                            Color32::BLACK,
-                        )
-                    });
+    #[allow(dead_code)]
+    pub fn with_grow_upward(mut self, grow_upward: bool) -> Self {
+        self.grow_upward = grow_upward;
+        self
+    }
 
-                    let text_pos = Pos2::new(
-                        x_offset + animated_rect.width() * 0.34,
-                        animated_rect.top() + animated_rect.height() * 0.3,
-                    );
+    pub fn with_easing(mut self, curve: impl Fn(f32) -> f32 + 'static) -> Self {
+        self.easing = Easing::new(curve, self.easing.resolution());
+        self
+    }
 
-                    let text_pos2 = Pos2::new(
-                        x_offset2 + animated_rect2.width() * 0.5,
-                        animated_rect2.top() + animated_rect2.height() * 0.3,
-                    );
+    #[allow(dead_code)]
+    pub fn with_version_overflow(mut self, overflow: VersionOverflow) -> Self {
+        self.version_overflow = overflow;
+        self
+    }
 
-                    ui.painter().galley_with_override_text_color(
-                        text_pos,
-                        galley.clone(),
-                        Color32::from_rgba_premultiplied(0, 0, 0, alpha),
-                    );
+    #[allow(dead_code)]
+    pub fn with_hover_fill(mut self, fill: Color32) -> Self {
+        self.hover_fill = Some(fill);
+        self
+    }
 
-                    ui.painter().galley_with_override_text_color(
-                        text_pos2,
-                        galley2.clone(),
-                        Color32::from_rgba_premultiplied(0, 0, 0, alpha),
-                    );
+    #[allow(dead_code)]
+    pub fn with_pressed_fill(mut self, fill: Color32) -> Self {
+        self.pressed_fill = Some(fill);
+        self
+    }
 
-                    if response.clicked() && !row.data.popup_open {
-                        row.data.editing = Editing::VERSION;
-                    }
+    #[allow(dead_code)]
+    pub fn with_click_behavior(mut self, behavior: ClickBehavior) -> Self {
+        self.click_behavior = behavior;
+        self
+    }
 
-                    if response2.clicked() {
-                        row.data.editing = Editing::PATH;
-                    }
-                });
-            }
-        });
+    #[allow(dead_code)]
+    pub fn with_selection_fill(mut self, fill: Color32) -> Self {
+        self.selection_fill = fill;
+        self
+    }
 
-        if needs_redraw {
-            ui.ctx().request_repaint();
+    // Time from the first row starting to the last row settling
+    #[allow(dead_code)]
+    pub fn total_entrance_time(&self) -> f32 {
+        if let Some(max) = self.max_concurrent_entrances {
+            let longest = self
+                .rows
+                .iter()
+                .map(|row| row.animation_time)
+                .fold(0.0, f32::max);
+            return self.rows.len().div_ceil(max) as f32 * longest;
         }
+        self.rows
+            .iter()
+            .map(|row| row.delay + row.animation_time)
+            .fold(0.0, f32::max)
     }
-}
 
-#[derive(Default)]
-struct AnimationApp {
-    row_list: AnimatedRowList,
-    popup_open: bool,
-}
+    #[allow(dead_code)]
+    pub fn stagger_clamped(&self) -> bool {
+        self.stagger_clamped
+    }
 
-impl AnimationApp {
-    pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
-        let mut rows = Vec::with_capacity(101);
-        for x in 0..=100 {
-            rows.push(RowData::new(
-                format!("GE-Proton9-{}", x),
-                format!("/some/path/{}", x),
-            ));
+    #[allow(dead_code)]
+    pub fn with_max_concurrent_entrances(mut self, max: usize) -> Self {
+        self.max_concurrent_entrances = Some(max.max(1));
+        for row in &mut self.rows {
+            row.queued = true;
         }
-        Self {
-            row_list: AnimatedRowList::new(rows, cc.egui_ctx.input(|i| i.time), 1.0, 0.1),
-            popup_open: false,
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn set_galley_memory_budget(&mut self, bytes: usize) {
+        self.galley_memory_budget = Some(bytes);
+    }
+
+    pub fn galley_memory(&self) -> usize {
+        self.rows.iter().map(|row| row.data.galley_memory()).sum()
+    }
+
+    // Drops the galleys of rows not drawn this frame, least recently drawn first, until the
+    // cache fits; rows on screen keep theirs even over budget, or they'd be rebuilt every frame
+    fn enforce_galley_memory_budget(&mut self, pass: u64) {
+        let Some(budget) = self.galley_memory_budget else {
+            return;
+        };
+        let mut total = self.galley_memory();
+        if total <= budget {
+            return;
+        }
+
+        let mut cached: Vec<usize> = (0..self.rows.len())
+            .filter(|&i| self.rows[i].galley_used < pass && self.rows[i].data.galley_memory() > 0)
+            .collect();
+        cached.sort_by_key(|&i| self.rows[i].galley_used);
+        for i in cached {
+            if total <= budget {
+                break;
+            }
+            let data = &mut self.rows[i].data;
+            total -= data.galley_memory();
+            data.galley_version = None;
+            data.galley_path = None;
+            data.galley_meta = None;
+            data.galley_badge = None;
         }
     }
-}
 
-impl eframe::App for AnimationApp {
-    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        puffin::GlobalProfiler::lock().new_frame();
-        puffin::profile_scope!("AnimationApp::update");
-        egui::CentralPanel::default().show(ctx, |ui| {
-            egui::ScrollArea::vertical().show(ui, |ui| {
-                self.row_list.show(ui);
-            });
-        });
+    #[allow(dead_code)]
+    pub fn with_focus_stroke(mut self, stroke: egui::Stroke) -> Self {
+        self.focus_stroke = stroke;
+        self
     }
-}
 
-fn start_puffin_server() {
-    puffin::set_scopes_on(true);
-    if let Ok(puffin_server) = puffin_http::Server::new("127.0.0.1:8585") {
-        std::process::Command::new("puffin_viewer")
-            .arg("--url")
-            .arg("127.0.0.1:8585")
-            .spawn()
-            .ok();
-        std::mem::forget(puffin_server);
+    #[allow(dead_code)]
+    pub fn with_hover_select_delay(mut self, seconds: f32) -> Self {
+        self.hover_select_delay = Some(seconds.max(0.0));
+        self
+    }
+
+    fn update_hover_select(&mut self, ui: &egui::Ui, hovered_row: Option<usize>, time: f64) {
+        let Some(delay) = self.hover_select_delay else {
+            return;
+        };
+        let Some(index) = hovered_row else {
+            self.hover_start = None;
+            return;
+        };
+        let (hovered, since, selected) = match self.hover_start {
+            Some(start) if start.0 == index => start,
+            _ => (index, time, false),
+        };
+        let waited = (time - since) as f32;
+        let select = !selected && waited >= delay;
+        if select {
+            for (i, row) in self.rows.iter_mut().enumerate() {
+                row.data.selected = i == index;
+            }
+        } else if !selected {
+            ui.ctx()
+                .request_repaint_after(Duration::from_secs_f32(delay - waited));
+        }
+        self.hover_start = Some((hovered, since, selected || select));
+    }
+
+    #[allow(dead_code)]
+    pub fn with_focus_follows_hover(mut self, follow: bool) -> Self {
+        self.focus_follows_hover = follow;
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn focused(&self) -> Option<usize> {
+        self.focused
+    }
+
+    #[allow(dead_code)]
+    pub fn set_focused(&mut self, index: Option<usize>) {
+        self.focused = index.filter(|&i| i < self.rows.len());
+    }
+
+    #[allow(dead_code)]
+    pub fn with_scroll_easing(mut self, curve: impl Fn(f32) -> f32 + 'static) -> Self {
+        self.scroll_easing = Easing::new(curve, self.scroll_easing.resolution());
+        self
+    }
+
+    // Smoothly scrolls the enclosing ScrollArea until the row is at the top of the view
+    pub fn scroll_to(&mut self, index: usize) {
+        if index < self.rows.len() {
+            self.scroll_target = Some(index);
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn with_delete_keys(mut self, delete: bool) -> Self {
+        self.delete_keys = delete;
+        self
+    }
+
+    // Delete or Backspace removes the selected rows, or the focused one if nothing is selected
+    fn handle_delete_keys(&mut self, ui: &egui::Ui, time: f64) {
+        if !self.delete_keys || ui.ctx().wants_keyboard_input() {
+            return;
+        }
+        let pressed =
+            ui.input(|i| i.key_pressed(egui::Key::Delete) || i.key_pressed(egui::Key::Backspace));
+        if !pressed {
+            return;
+        }
+
+        let removable =
+            |row: &AnimatedRow| !row.data.disabled && !row.hidden && row.exiting.is_none();
+        let mut targets: Vec<usize> = (0..self.rows.len())
+            .filter(|&i| self.rows[i].data.selected && removable(&self.rows[i]))
+            .collect();
+        if targets.is_empty() {
+            targets.extend(
+                self.focused
+                    .filter(|&i| self.rows.get(i).is_some_and(removable)),
+            );
+        }
+        for index in targets {
+            self.remove_row(index, time);
+        }
+        self.ensure_selection();
+    }
+
+    // Up and down move the focus through the visible rows in on-screen order
+    fn handle_focus_keys(&mut self, ui: &egui::Ui) {
+        if ui.input(|i| i.pointer.is_moving()) {
+            self.keyboard_focus = false;
+        }
+        if ui.ctx().wants_keyboard_input() {
+            return;
+        }
+        let (up, down) = ui.input(|i| {
+            (
+                i.key_pressed(egui::Key::ArrowUp),
+                i.key_pressed(egui::Key::ArrowDown),
+            )
+        });
+        if up == down {
+            return;
+        }
+
+        let visible: Vec<usize> = (0..self.rows.len())
+            .map(|i| self.layout_index(i))
+            .filter(|&index| !self.rows[index].hidden)
+            .collect();
+        let position = self
+            .focused
+            .and_then(|focused| visible.iter().position(|&index| index == focused));
+        let position = match position {
+            Some(position) if up => position.saturating_sub(1),
+            Some(position) => (position + 1).min(visible.len().saturating_sub(1)),
+            None => 0,
+        };
+        if let Some(&index) = visible.get(position) {
+            self.focused = Some(index);
+            self.scroll_to_focused = true;
+            self.keyboard_focus = true;
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn with_row_background(mut self, fill: Color32) -> Self {
+        self.row_background = Some(fill);
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn with_click_through_background(mut self, click_through: bool) -> Self {
+        self.click_through_background = click_through;
+        self
+    }
+
+    // The family has to be registered with the context's fonts, e.g. one covering CJK
+    #[allow(dead_code)]
+    pub fn with_fallback_font(mut self, family: egui::FontFamily) -> Self {
+        self.fallback_font = Some(family);
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn with_compact_when_scrolling(mut self, scale: f32) -> Self {
+        self.compact_when_scrolling = Some(scale.clamp(0.0, 1.0));
+        self
+    }
+
+    // Eases the row height scale towards the compact size while scroll input keeps arriving
+    fn update_compact(&mut self, ui: &egui::Ui, list_id: egui::Id) {
+        let Some(compact) = self.compact_when_scrolling else {
+            self.height_scale = 1.0;
+            return;
+        };
+        let (time, scrolled) = ui.input(|i| (i.time, i.raw_scroll_delta.y != 0.0));
+        if scrolled && ui.rect_contains_pointer(ui.clip_rect()) {
+            self.last_scroll_time = time;
+        }
+        let held = (COMPACT_HOLD as f64 - (time - self.last_scroll_time)) as f32;
+        if held > 0.0 {
+            ui.ctx()
+                .request_repaint_after(Duration::from_secs_f32(held));
+        }
+        let t = ui.ctx().animate_bool_with_time(
+            list_id.with("animated_row_list_compact"),
+            held > 0.0,
+            COMPACT_DURATION,
+        );
+        self.height_scale = 1.0 - (1.0 - compact) * t;
+    }
+
+    pub fn with_stack_below(mut self, width: f32) -> Self {
+        self.stack_below = Some(width);
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn with_single_interaction(mut self, single: bool) -> Self {
+        self.single_interaction = single;
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn with_meta_column(mut self, width_fraction: f32) -> Self {
+        self.meta_column = Some(width_fraction.clamp(0.0, 1.0));
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn with_reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn with_snap_reorder(mut self, snap: bool) -> Self {
+        self.snap_reorder = snap;
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn with_selection_fade(mut self, seconds: f32) -> Self {
+        self.selection_fade = seconds;
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn with_require_selection(mut self, require: bool) -> Self {
+        self.require_selection = require;
+        self
+    }
+
+    fn ensure_selection(&mut self) {
+        let selected = |row: &AnimatedRow| row.data.selected && row.exiting.is_none();
+        if !self.require_selection || self.rows.iter().any(selected) {
+            return;
+        }
+        if let Some(row) = self
+            .rows
+            .iter_mut()
+            .find(|row| !row.data.disabled && row.exiting.is_none())
+        {
+            row.data.selected = true;
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn clicked_row(&self) -> Option<usize> {
+        self.clicked_row
+    }
+
+    // Indices change with sorting and reordering, so prefer the version based accessors below
+    #[allow(dead_code)]
+    pub fn selected(&self) -> impl Iterator<Item = usize> + '_ {
+        self.rows
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| row.data.selected)
+            .map(|(i, _)| i)
+    }
+
+    // Selection lives on the row itself, so it follows the row through sorts and filters
+    pub fn selected_versions(&self) -> impl Iterator<Item = &str> + '_ {
+        self.rows
+            .iter()
+            .filter(|row| row.data.selected)
+            .map(|row| row.data.version.as_str())
+    }
+
+    pub fn set_selected_versions<'a>(&mut self, versions: impl IntoIterator<Item = &'a str>) {
+        let versions: Vec<&str> = versions.into_iter().collect();
+        for row in &mut self.rows {
+            row.data.selected = versions.contains(&row.data.version.as_str());
+        }
+        self.ensure_selection();
+    }
+
+    pub fn index_of_version(&self, version: &str) -> Option<usize> {
+        self.rows.iter().position(|row| row.data.version == version)
+    }
+
+    pub fn set_easing(&mut self, curve: impl Fn(f32) -> f32 + 'static) {
+        self.easing = Easing::new(curve, self.easing.resolution());
+    }
+
+    // Number of samples taken from the easing curve; more is closer to the exact curve
+    #[allow(dead_code)]
+    pub fn with_easing_resolution(mut self, resolution: usize) -> Self {
+        self.easing = self.easing.with_resolution(resolution);
+        self
+    }
+
+    // Chains lists on one timeline, e.g. offset the second list by the first's total_entrance_time
+    #[allow(dead_code)]
+    pub fn with_start_offset(mut self, offset: f32) -> Self {
+        self.start_offset = offset.max(0.0);
+        self.restart(self.start_time);
+        self
+    }
+
+    // Replays the entrance for every row, rows the queue already let out lose their stagger
+    pub fn restart(&mut self, start_time: f64) {
+        self.start_time = start_time;
+        for row in &mut self.rows {
+            row.start_time = start_time + self.start_offset as f64;
+            row.queued = self.max_concurrent_entrances.is_some();
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn with_append_limit(mut self, limit: usize) -> Self {
+        self.append_limit = Some(limit.max(1));
+        self
+    }
+
+    // Queues rows to be added at the end of the list over the next shows
+    #[allow(dead_code)]
+    pub fn push_rows(&mut self, rows: impl IntoIterator<Item = RowData>) {
+        self.pending_rows.extend(rows);
+    }
+
+    #[allow(dead_code)]
+    pub fn pending_rows(&self) -> usize {
+        self.pending_rows.len()
+    }
+
+    // Admitted rows start their entrance on arrival rather than on the list's stagger
+    fn admit_pending_rows(&mut self, time: f64) {
+        let count = self
+            .append_limit
+            .unwrap_or(usize::MAX)
+            .min(self.pending_rows.len());
+        for data in self.pending_rows.drain(..count) {
+            let mut row =
+                AnimatedRow::new(data, time, self.animation_duration, 0.0, self.row_height);
+            row.hidden = !row.data.matches(&self.filter);
+            row.queued = self.max_concurrent_entrances.is_some();
+            self.rows.push(row);
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn with_looping(mut self, mode: LoopMode) -> Self {
+        self.looping = Some(mode);
+        self
+    }
+
+    // Wobbles the row sideways, e.g. to reject an invalid edit
+    #[allow(dead_code)]
+    pub fn shake(&mut self, index: usize, time: f64) {
+        if let Some(row) = self.rows.get_mut(index) {
+            row.shake_start = Some(time);
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn with_removal_style(mut self, style: RemovalStyle) -> Self {
+        self.removal_style = style;
+        self
+    }
+
+    // Animates the row out, it stays in `rows` until the removal has finished
+    pub fn remove_row(&mut self, index: usize, time: f64) {
+        let Some(row) = self.rows.get_mut(index) else {
+            return;
+        };
+        if row.exiting.is_none() {
+            row.exiting = Some(time);
+            if self.removal_style == RemovalStyle::Collapse {
+                row.height.animate_to(0.0, time, REMOVAL_DURATION);
+            }
+        }
+    }
+
+    fn finish_removals(&mut self, time: f64) {
+        let done = |row: &AnimatedRow| {
+            row.exiting
+                .is_some_and(|start| time - start >= REMOVAL_DURATION as f64)
+        };
+        if !self.rows.iter().any(done) {
+            return;
+        }
+        let order: Vec<usize> = (0..self.rows.len())
+            .filter(|&i| !done(&self.rows[i]))
+            .collect();
+        self.drag = None;
+        self.hover_start = None;
+        self.clicked_row = self
+            .clicked_row
+            .and_then(|clicked| order.iter().position(|&i| i == clicked));
+        self.apply_order(&order);
+    }
+
+    // Shows the first `fraction` of the rows, for lists that fill in with some external progress
+    #[allow(dead_code)]
+    pub fn reveal_fraction(&mut self, fraction: f32) {
+        self.reveal_fraction = Some(fraction.clamp(0.0, 1.0));
+    }
+
+    fn revealed_rows(&self) -> usize {
+        match self.reveal_fraction {
+            Some(fraction) => (fraction * self.rows.len() as f32).round() as usize,
+            None => self.rows.len(),
+        }
+    }
+
+    // Rows past the revealed fraction wait in the queue, rows reaching it start their entrance
+    fn apply_reveal_fraction(&mut self, time: f64) {
+        if self.reveal_fraction.is_none() {
+            return;
+        }
+        let revealed = self.revealed_rows();
+        let start_now = self.max_concurrent_entrances.is_none();
+        for (i, row) in self.rows.iter_mut().enumerate() {
+            if i >= revealed {
+                row.queued = true;
+            } else if row.queued && start_now {
+                row.queued = false;
+                row.start_time = time;
+                row.delay = 0.0;
+            }
+        }
+    }
+
+    fn start_queued_entrances(&mut self, time: f64) {
+        let Some(max) = self.max_concurrent_entrances else {
+            return;
+        };
+        if time < self.start_time + self.start_offset as f64 {
+            return;
+        }
+        let running = self.rows.iter().filter(|row| row.is_entering(time)).count();
+        let free = max.saturating_sub(running);
+        let revealed = self.revealed_rows();
+        for row in self
+            .rows
+            .iter_mut()
+            .take(revealed)
+            .filter(|row| row.queued)
+            .take(free)
+        {
+            row.queued = false;
+            row.start_time = time;
+            row.delay = 0.0;
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn animate_row_height(&mut self, index: usize, height: f32, time: f64, duration: f32) {
+        if let Some(row) = self.rows.get_mut(index) {
+            row.height.animate_to(height, time, duration);
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn animate_row_fill(&mut self, index: usize, fill: Color32, time: f64, duration: f32) {
+        if let Some(row) = self.rows.get_mut(index) {
+            row.fill.animate_to(fill, time, duration);
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn with_reset_scroll_on_filter(mut self, reset: bool) -> Self {
+        self.reset_scroll_on_filter = reset;
+        self
+    }
+
+    pub fn with_filter_debounce(mut self, seconds: f32) -> Self {
+        self.filter_debounce = seconds.max(0.0);
+        self
+    }
+
+    // Applies the filter once no other has been typed for `filter_debounce` seconds
+    pub fn type_filter(&mut self, filter: &str, time: f64) {
+        if self.filter_debounce > 0.0 {
+            self.pending_filter = Some((filter.to_owned(), time));
+        } else {
+            self.set_filter(filter);
+        }
+    }
+
+    fn apply_pending_filter(&mut self, ui: &egui::Ui) {
+        let Some((_, typed_at)) = &self.pending_filter else {
+            return;
+        };
+        let waited = (ui.input(|i| i.time) - typed_at) as f32;
+        if waited >= self.filter_debounce {
+            if let Some((filter, _)) = self.pending_filter.take() {
+                self.set_filter(&filter);
+            }
+        } else {
+            ui.ctx()
+                .request_repaint_after(Duration::from_secs_f32(self.filter_debounce - waited));
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn filter_recomputes(&self) -> usize {
+        self.filter_recomputes
+    }
+
+    pub fn set_filter(&mut self, filter: &str) {
+        self.pending_filter = None;
+        let filter = filter.to_lowercase();
+        if self.reset_scroll_on_filter && filter != self.filter {
+            self.reset_scroll = true;
+            self.scroll_target = None;
+            self.scroll = None;
+        }
+        self.filter = filter;
+        self.filter_recomputes += 1;
+        for row in &mut self.rows {
+            let hidden = !row.data.matches(&self.filter);
+            row.revealing |= row.hidden && !hidden;
+            row.hidden = hidden;
+        }
+    }
+
+    // Rows coming back from a filter grow from nothing and replay their entrance,
+    // waiting for a free slot when entrances are capped
+    fn start_reveals(&mut self, time: f64) {
+        let capped = self.max_concurrent_entrances.is_some();
+        for row in self.rows.iter_mut().filter(|row| row.revealing) {
+            row.revealing = false;
+            let height = row.height.target;
+            row.height = PropertyAnimation::new(0.0);
+            row.height.animate_to(height, time, REVEAL_DURATION);
+            row.start_time = time;
+            row.delay = 0.0;
+            row.queued = capped;
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn first_visible_index(&self, ui: &egui::Ui) -> Option<usize> {
+        let time = ui.input(|i| i.time);
+        let spacing = ui.spacing().item_spacing.y;
+        let scroll_offset = self.scroll_offset(ui);
+
+        let mut row_bottom = 0.0;
+        for i in 0..self.rows.len() {
+            let index = self.layout_index(i);
+            if self.rows[index].hidden {
+                continue;
+            }
+            row_bottom += self.rows[index].height.value(time) * self.height_scale;
+            if row_bottom > scroll_offset {
+                return Some(index);
+            }
+            row_bottom += spacing;
+        }
+        None
+    }
+
+    // How far the enclosing ScrollArea is scrolled past the top of the list,
+    // its clip rect reaches `clip_rect_margin` above the visible area
+    fn scroll_offset(&self, ui: &egui::Ui) -> f32 {
+        ui.clip_rect().top() + ui.visuals().clip_rect_margin - self.list_top
+    }
+
+    // Maps a position in the on-screen order to an index into `rows`
+    #[inline]
+    fn layout_index(&self, i: usize) -> usize {
+        if self.grow_upward {
+            self.rows.len() - 1 - i
+        } else {
+            i
+        }
+    }
+
+    fn rows_height(&self, row_height: impl Fn(&AnimatedRow) -> f32) -> f32 {
+        let visible = || self.rows.iter().filter(|row| !row.hidden);
+        let heights: f32 = visible().map(row_height).sum();
+        heights + self.row_spacing * visible().count().saturating_sub(1) as f32
+    }
+
+    // Height of the whole list once every row has settled at its target height
+    #[allow(dead_code)]
+    pub fn content_height(&self) -> f32 {
+        self.rows_height(|row| row.height.target)
+    }
+
+    #[allow(dead_code)]
+    pub fn last_frame_cost(&self) -> Duration {
+        self.last_frame_cost
+    }
+
+    pub fn show(&mut self, ui: &mut egui::Ui) {
+        let start = Instant::now();
+        self.show_rows(ui);
+        self.last_frame_cost = start.elapsed();
+    }
+
+    // Moves the dragged row to its slot on release, returns true while a drag is in progress
+    fn update_drag(&mut self, ui: &egui::Ui, time: f64, pointer_y: Option<f32>) -> bool {
+        if ui.input(|i| !i.pointer.primary_down()) {
+            if let Some(drag) = self.drag.take()
+                && let Some(&(target, _, _)) = self.slots.get(drag.slot)
+            {
+                let mut order: Vec<usize> = (0..self.rows.len()).collect();
+                let moved = order.remove(drag.index);
+                order.insert(target, moved);
+                self.apply_order(&order);
+            }
+            return false;
+        }
+
+        let (Some(drag), Some(y)) = (&mut self.drag, pointer_y) else {
+            return self.drag.is_some();
+        };
+        // The dragged row's own slot doesn't count, it is the one being moved out of the way
+        let slot = self
+            .slots
+            .iter()
+            .filter(|&&(index, top, height)| {
+                index != drag.index && self.list_top + top + height / 2.0 < y
+            })
+            .count()
+            .min(self.slots.len().saturating_sub(1));
+        if slot != drag.slot {
+            drag.slot = slot;
+            drag.slot_top
+                .animate_to(self.slots[slot].1, time, SNAP_DURATION);
+        }
+        true
+    }
+
+    fn show_rows(&mut self, ui: &mut egui::Ui) {
+        // Without a salt the list is told apart by where it sits in the Ui
+        let list_id = match self.id_salt {
+            Some(salt) => ui.id().with(salt),
+            None => ui.next_auto_id(),
+        };
+        self.apply_pending_filter(ui);
+        if !self.pending_rows.is_empty() {
+            self.admit_pending_rows(ui.input(|i| i.time));
+            ui.ctx().request_repaint();
+        }
+
+        if self.rows.iter().all(|row| row.hidden) {
+            if let Some(on_empty) = &self.on_empty {
+                on_empty(ui);
+            }
+            return;
+        }
+
+        let mut time = ui.input(|i| i.time);
+        if self.pause_on_focus_loss {
+            let focused = ui.input(|i| i.focused);
+            match self.paused_at {
+                None if !focused => self.paused_at = Some(time),
+                Some(paused_at) if focused => {
+                    let paused = time - paused_at;
+                    // The list's own start moves too, or a start offset would run out during the pause
+                    self.start_time += paused;
+                    for row in &mut self.rows {
+                        row.shift_start_time(paused);
+                    }
+                    if let Some(scroll) = &mut self.scroll {
+                        scroll.start_time += paused;
+                    }
+                    if let Some((_, since, _)) = &mut self.hover_start {
+                        *since += paused;
+                    }
+                    self.paused_at = None;
+                }
+                _ => {}
+            }
+            time = self.paused_at.unwrap_or(time);
+        }
+
+        self.apply_reveal_fraction(time);
+        self.start_reveals(time);
+        self.start_queued_entrances(time);
+
+        // Collapsed containers have nothing to paint into, and zero-width cells break the text math
+        let width = ui.available_width();
+        if width.is_nan() || width <= 0.0 {
+            return;
+        }
+
+        self.handle_focus_keys(ui);
+        self.handle_delete_keys(ui, time);
+
+        let mut needs_redraw = false;
+        let mut hovered_row = None;
+        self.ensure_selection();
+        let selected_count = self.rows.iter().filter(|row| row.data.selected).count();
+        let revealed = self.revealed_rows();
+        let mut viewed = false;
+        let feathering = self.feathering;
+        self.clicked_row = None;
+        self.row_spacing = ui.spacing().item_spacing.y;
+        self.update_compact(ui, list_id);
+        let height_scale = self.height_scale;
+        let content_height = self.rows_height(|row| row.height.value(time) * height_scale);
+        let scroll_target = self
+            .scroll_target
+            .take()
+            .map(|index| self.row_tops(time)[index]);
+
+        // Remembered in egui memory as well, so a list rebuilt for the same view stays settled
+        let played_id = list_id.with("animated_row_list_played");
+        let pointer_y = ui.input(|i| i.pointer.interact_pos()).map(|pos| pos.y);
+        // Galley use is tracked by pass, the clock stands still while paused
+        let pass = ui.ctx().cumulative_pass_nr();
+        let settled = self.play_once
+            && (self.played || ui.data(|d| d.get_temp::<bool>(played_id).unwrap_or(false)));
+
+        ui.vertical(|ui| {
+            if self.grow_upward {
+                ui.add_space((ui.available_height() - content_height).max(0.0));
+            }
+            self.list_top = ui.cursor().top();
+            self.slots.clear();
+
+            let scroll_offset = self.scroll_offset(ui);
+            if let Some(to) = scroll_target {
+                self.scroll = Some(ScrollTo {
+                    from: scroll_offset,
+                    to,
+                    start_time: time,
+                    requested: scroll_offset,
+                });
+            }
+            if let Some(scroll) = &mut self.scroll {
+                let t = ((time - scroll.start_time) as f32 / SCROLL_DURATION).min(1.0);
+                let wanted = scroll.from + (scroll.to - scroll.from) * self.scroll_easing.sample(t);
+                // Already eased here, so egui's own scroll animation would only lag behind
+                ui.scroll_with_delta_animation(
+                    Vec2::new(0.0, scroll.requested - wanted),
+                    egui::style::ScrollAnimation::none(),
+                );
+                scroll.requested = wanted;
+                if t < 1.0 {
+                    needs_redraw = true;
+                } else {
+                    self.scroll = None;
+                }
+            }
+            if std::mem::take(&mut self.reset_scroll) {
+                ui.scroll_with_delta_animation(
+                    Vec2::new(0.0, scroll_offset),
+                    egui::style::ScrollAnimation::none(),
+                );
+            }
+
+            let drag_top = match (&self.drag, pointer_y) {
+                (Some(drag), _) if self.snap_reorder => {
+                    Some(self.list_top + drag.slot_top.value(time))
+                }
+                (Some(drag), Some(y)) => Some(y - drag.grab_offset),
+                _ => None,
+            };
+
+            for i in 0..self.rows.len() {
+                let index = if self.grow_upward {
+                    self.rows.len() - 1 - i
+                } else {
+                    i
+                };
+                let row = &mut self.rows[index];
+                if row.hidden {
+                    continue;
+                }
+                let row_output = ui.horizontal(|ui| {
+                    let progress = if settled {
+                        1.0
+                    } else {
+                        row.get_progress(time, &self.easing, self.looping)
+                    };
+                    needs_redraw |= self.looping.is_some();
+                    // Rows held back by reveal_fraction wait for the caller, not the clock
+                    needs_redraw |= progress < 1.0 && index < revealed;
+
+                    let exit = row
+                        .exiting
+                        .map(|start| ((time - start) as f32 / REMOVAL_DURATION).min(1.0));
+                    let progress = match exit {
+                        Some(exit) if self.removal_style == RemovalStyle::SlideOut => {
+                            progress.min(self.easing.sample(1.0 - exit))
+                        }
+                        _ => progress,
+                    };
+                    let fade = exit.map_or(1.0, |exit| 1.0 - exit);
+                    // Only the position may overshoot with a springy curve, colours stay within 0..=1
+                    let opacity = progress.clamp(0.0, 1.0) * fade;
+                    needs_redraw |= exit.is_some();
+                    needs_redraw |= row.height.is_animating(time)
+                        || row.fill.is_animating(time)
+                        || row.sort_offset.is_animating(time);
+
+                    let sense = if row.data.disabled || exit.is_some() {
+                        egui::Sense::hover()
+                    } else if self.interactive_while_animating || progress >= INTERACTIVE_PROGRESS {
+                        if self.reorderable {
+                            egui::Sense::click_and_drag()
+                        } else {
+                            egui::Sense::click()
+                        }
+                    } else {
+                        egui::Sense::hover()
+                    };
+
+                    let (_id, full_rect) = ui.allocate_space(Vec2::new(
+                        ui.available_width(),
+                        row.height.value(time) * self.height_scale,
+                    ));
+
+                    // Narrow lists put the path under the version instead of beside it
+                    let stacked = self
+                        .stack_below
+                        .is_some_and(|width| full_rect.width() < width);
+                    let meta_width = if stacked {
+                        0.0
+                    } else {
+                        full_rect.width() * self.meta_column.unwrap_or(0.0)
+                    };
+                    let half_width = (full_rect.width() - meta_width) / 2.0;
+                    let (cell_size, path_top, version_inset, path_inset) = if stacked {
+                        let size = Vec2::new(full_rect.width(), full_rect.height() / 2.0);
+                        (size, size.y, 0.05, 0.05)
+                    } else {
+                        (Vec2::new(half_width, full_rect.height()), 0.0, 0.34, 0.5)
+                    };
+
+                    // Growing upward slides rows down into place instead of in from the side
+                    let (x_progress, y_offset) = if self.grow_upward {
+                        (1.0, -(1.0 - progress) * full_rect.height())
+                    } else {
+                        (progress, 0.0)
+                    };
+                    self.slots
+                        .push((index, full_rect.top() - self.list_top, full_rect.height()));
+                    let dragged = self.drag.as_ref().is_some_and(|drag| drag.index == index);
+                    let top = match drag_top {
+                        Some(drag_top) if dragged => drag_top,
+                        _ => full_rect.top() + y_offset + row.sort_offset.value(time),
+                    };
+
+                    if self.focused == Some(index) && std::mem::take(&mut self.scroll_to_focused) {
+                        ui.scroll_to_rect(full_rect, None);
+                    }
+
+                    // Rows scrolled out of view keep their space but skip painting and text layout
+                    let drawn_rect = full_rect.translate(Vec2::new(0.0, top - full_rect.top()));
+                    if !ui.is_rect_visible(drawn_rect.union(full_rect)) {
+                        return false;
+                    }
+                    row.galley_used = pass;
+                    // Set when a galley is built, the budget is checked as soon as the row is done
+                    let mut laid_out = false;
+
+                    let background_sense = if self.click_through_background {
+                        egui::Sense::hover()
+                    } else {
+                        egui::Sense::click()
+                    };
+                    if let Some(background) = self.row_background {
+                        // Interacted before the cells, so the cells stay on top of it,
+                        // a single interaction covers the background itself
+                        if !self.single_interaction {
+                            let background_response = ui.interact(
+                                full_rect,
+                                ui.next_auto_id().with((&row.data.version, "background")),
+                                background_sense,
+                            );
+                            if background_response.clicked() {
+                                self.focused = Some(index);
+                            }
+                        }
+                        ui.painter().rect_filled(
+                            full_rect,
+                            0.0,
+                            background.gamma_multiply(opacity),
+                        );
+                    }
+
+                    let shake_x = row.shake_offset(time);
+                    needs_redraw |= row.shake_start.is_some();
+
+                    let start_x = full_rect.left() + half_width;
+                    let target_x = full_rect.left();
+                    let x_offset = start_x + (target_x - start_x) * x_progress + shake_x;
+
+                    let start_x2 = full_rect.right();
+                    let target_x2 = if stacked { target_x } else { start_x };
+                    let x_offset2 = start_x2 + (target_x2 - start_x2) * x_progress + shake_x;
+
+                    let animated_rect = Rect::from_min_size(Pos2::new(x_offset, top), cell_size);
+                    let animated_rect2 =
+                        Rect::from_min_size(Pos2::new(x_offset2, top + path_top), cell_size);
+
+                    let (response, response2) = if self.single_interaction {
+                        let cells = animated_rect.union(animated_rect2);
+                        let (row_rect, row_sense) = if self.row_background.is_some() {
+                            (full_rect.union(cells), sense | background_sense)
+                        } else {
+                            (cells, sense)
+                        };
+                        let row_response = ui.interact(
+                            row_rect,
+                            ui.next_auto_id().with(&row.data.version),
+                            row_sense,
+                        );
+                        // A click that no clickable cell took lands on the background
+                        let on_cell = |pos: Pos2| {
+                            sense.senses_click()
+                                && (animated_rect.contains(pos) || animated_rect2.contains(pos))
+                        };
+                        if self.row_background.is_some()
+                            && row_response.clicked()
+                            && !row_response.interact_pointer_pos().is_some_and(on_cell)
+                        {
+                            self.focused = Some(index);
+                        }
+                        (
+                            cell_response(&row_response, animated_rect, sense),
+                            cell_response(&row_response, animated_rect2, sense),
+                        )
+                    } else {
+                        (
+                            ui.interact(
+                                animated_rect,
+                                ui.next_auto_id().with(&row.data.version),
+                                sense,
+                            ),
+                            ui.interact(
+                                animated_rect2,
+                                ui.next_auto_id().with(&row.data.path),
+                                sense,
+                            ),
+                        )
+                    };
+
+                    if let Some(y) =
+                        pointer_y.filter(|_| response.drag_started() || response2.drag_started())
+                    {
+                        self.drag = Some(RowDrag {
+                            index,
+                            slot: self.slots.len() - 1,
+                            grab_offset: y - full_rect.top(),
+                            slot_top: PropertyAnimation::new(full_rect.top() - self.list_top),
+                        });
+                    }
+
+                    if self.focus_follows_hover
+                        && !self.keyboard_focus
+                        && (response.hovered() || response2.hovered())
+                    {
+                        self.focused = Some(index);
+                    }
+
+                    if self.hover_select_delay.is_some()
+                        && !row.data.disabled
+                        && (response.hovered() || response2.hovered())
+                    {
+                        hovered_row = Some(index);
+                    }
+
+                    let (response, response2) = match &row.data.disabled_reason {
+                        Some(reason) if row.data.disabled => (
+                            response.on_hover_text(reason),
+                            response2.on_hover_text(reason),
+                        ),
+                        _ => (response, response2),
+                    };
+
+                    let alpha = (255.0 * opacity) as u8;
+                    // Disabled rows are drawn at half opacity
+                    let alpha = if row.data.disabled { alpha / 2 } else { alpha };
+                    let selection = ui.ctx().animate_bool_with_time(
+                        ui.id()
+                            .with(("row_selection", &row.data.version, &row.data.path)),
+                        row.data.selected,
+                        self.selection_fade,
+                    );
+                    let base_fill = row.fill.value(time).lerp(self.selection_fill, selection);
+                    let base_fill2 = Color32::from_gray(180).lerp(self.selection_fill, selection);
+                    let fill = cell_fill(&response, base_fill, self.hover_fill, self.pressed_fill);
+                    let fill2 =
+                        cell_fill(&response2, base_fill2, self.hover_fill, self.pressed_fill);
+
+                    // Direct painting to avoid allocations
+                    ui.painter().add(row_rect_shape(
+                        ui,
+                        animated_rect,
+                        Color32::from_rgba_unmultiplied(fill.r(), fill.g(), fill.b(), alpha),
+                        feathering,
+                    ));
+
+                    ui.painter().add(row_rect_shape(
+                        ui,
+                        animated_rect2,
+                        Color32::from_rgba_unmultiplied(fill2.r(), fill2.g(), fill2.b(), alpha),
+                        feathering,
+                    ));
+
+                    if let Some(stroke) = self.row_stroke {
+                        let stroke =
+                            egui::Stroke::new(stroke.width, stroke.color.gamma_multiply(opacity));
+                        for rect in [animated_rect, animated_rect2] {
+                            ui.painter()
+                                .rect_stroke(rect, 0.0, stroke, egui::StrokeKind::Inside);
+                        }
+                    }
+
+                    // Drawn over the fills so a selected row still shows its focus
+                    if self.focused == Some(index) {
+                        let stroke = egui::Stroke::new(
+                            self.focus_stroke.width,
+                            self.focus_stroke.color.gamma_multiply(opacity),
+                        );
+                        ui.painter().rect_stroke(
+                            animated_rect.union(animated_rect2),
+                            0.0,
+                            stroke,
+                            egui::StrokeKind::Inside,
+                        );
+                    }
+
+                    let text_pos = Pos2::new(
+                        x_offset + animated_rect.width() * version_inset,
+                        animated_rect.top() + animated_rect.height() * 0.3,
+                    );
+                    let text_width = animated_rect.right() - text_pos.x;
+
+                    // The ellipsis depends on the cell width, so resizing has to lay it out again
+                    let ellipsis = self.version_overflow == VersionOverflow::Ellipsis;
+                    if ellipsis
+                        && row
+                            .data
+                            .galley_version
+                            .as_ref()
+                            .is_some_and(|galley| galley.job.wrap.max_width != text_width)
+                    {
+                        row.data.galley_version = None;
+                    }
+
+                    // Cache and reuse text galley
+                    let galley = row.data.galley_version.get_or_insert_with(|| {
+                        laid_out = true;
+                        let mut job = egui::text::LayoutJob::simple_singleline(
+                            row.data.version.clone(),
+                            row_font(ui, &row.data.version, &self.fallback_font),
+                            Color32::BLACK,
+                        );
+                        if ellipsis {
+                            job.wrap = egui::text::TextWrapping::truncate_at_width(text_width);
+                        }
+                        ui.painter().layout_job(job)
+                    });
+
+                    let galley2 = row.data.galley_path.get_or_insert_with(|| {
+                        laid_out = true;
+                        ui.painter().layout_no_wrap(
+                            row.data.path.clone(),
+                            row_font(ui, &row.data.path, &self.fallback_font),
+                            Color32::BLACK,
+                        )
+                    });
+
+                    let text_pos2 = Pos2::new(
+                        x_offset2 + animated_rect2.width() * path_inset,
+                        animated_rect2.top() + animated_rect2.height() * 0.3,
+                    );
+
+                    let marquee = self.version_overflow == VersionOverflow::MarqueeOnHover
+                        && response.hovered()
+                        && galley.size().x > text_width;
+                    let marquee_offset = if marquee {
+                        needs_redraw = true;
+                        let start = *row.marquee_start.get_or_insert(time);
+                        ((time - start) as f32 * MARQUEE_SPEED) % (galley.size().x + MARQUEE_GAP)
+                    } else {
+                        row.marquee_start = None;
+                        0.0
+                    };
+
+                    let version_painter = ui.painter().with_clip_rect(animated_rect);
+                    version_painter.galley_with_override_text_color(
+                        text_pos - Vec2::new(marquee_offset, 0.0),
+                        galley.clone(),
+                        Color32::from_rgba_premultiplied(0, 0, 0, alpha),
+                    );
+                    if marquee_offset > 0.0 {
+                        // Second copy scrolling in behind the first
+                        version_painter.galley_with_override_text_color(
+                            text_pos
+                                + Vec2::new(galley.size().x + MARQUEE_GAP - marquee_offset, 0.0),
+                            galley.clone(),
+                            Color32::from_rgba_premultiplied(0, 0, 0, alpha),
+                        );
+                    }
+
+                    ui.painter().galley_with_override_text_color(
+                        text_pos2,
+                        galley2.clone(),
+                        Color32::from_rgba_premultiplied(0, 0, 0, alpha),
+                    );
+
+                    // Metadata slides in from the right edge to sit after the path
+                    if self.meta_column.is_some() && !stacked {
+                        let start_x3 = full_rect.right();
+                        let target_x3 = full_rect.left() + half_width * 2.0;
+                        let x_offset3 = start_x3 + (target_x3 - start_x3) * x_progress + shake_x;
+                        let animated_rect3 = Rect::from_min_size(
+                            Pos2::new(x_offset3, top),
+                            Vec2::new(meta_width, full_rect.height()),
+                        );
+                        ui.painter().add(row_rect_shape(
+                            ui,
+                            animated_rect3,
+                            Color32::from_rgba_unmultiplied(210, 210, 210, alpha),
+                            feathering,
+                        ));
+
+                        if let Some(meta) = &row.data.meta {
+                            let galley3 = row.data.galley_meta.get_or_insert_with(|| {
+                                laid_out = true;
+                                ui.painter().layout_no_wrap(
+                                    meta.clone(),
+                                    row_font(ui, meta, &self.fallback_font),
+                                    Color32::BLACK,
+                                )
+                            });
+                            ui.painter()
+                                .with_clip_rect(animated_rect3)
+                                .galley_with_override_text_color(
+                                    Pos2::new(
+                                        x_offset3 + animated_rect3.width() * 0.1,
+                                        animated_rect3.top() + animated_rect3.height() * 0.3,
+                                    ),
+                                    galley3.clone(),
+                                    Color32::from_rgba_premultiplied(0, 0, 0, alpha),
+                                );
+                        }
+                    }
+
+                    // The badge stays put at the right edge and only fades in
+                    if let Some(badge) = &row.data.badge {
+                        let galley4 = row.data.galley_badge.get_or_insert_with(|| {
+                            laid_out = true;
+                            ui.painter().layout_no_wrap(
+                                badge.clone(),
+                                egui::FontId::new(14.0, egui::FontFamily::Proportional),
+                                Color32::BLACK,
+                            )
+                        });
+                        let badge_pos = Pos2::new(
+                            full_rect.right() - full_rect.height() * 0.3 - galley4.size().x
+                                + shake_x,
+                            top + (full_rect.height() - galley4.size().y) / 2.0,
+                        );
+                        ui.painter().galley_with_override_text_color(
+                            badge_pos,
+                            galley4.clone(),
+                            Color32::from_rgba_premultiplied(0, 0, 0, alpha),
+                        );
+                    }
+
+                    if response.clicked() || response2.clicked() {
+                        row.last_viewed = time;
+                        viewed = true;
+                    }
+
+                    if response.clicked() {
+                        self.clicked_row = Some(index);
+                        match self.click_behavior {
+                            // The last selected row can't be deselected when a selection is required
+                            ClickBehavior::Select => {
+                                row.data.selected = !row.data.selected
+                                    || (self.require_selection && selected_count <= 1);
+                            }
+                            ClickBehavior::Edit => row.data.editing = Editing::VERSION,
+                            ClickBehavior::Custom => {}
+                        }
+                    }
+
+                    if response2.clicked() {
+                        row.data.editing = Editing::PATH;
+                    }
+                    laid_out
+                });
+                if row_output.inner {
+                    self.enforce_galley_memory_budget(pass);
+                }
+            }
+        });
+
+        needs_redraw |= self.update_drag(ui, time, pointer_y);
+        self.update_hover_select(ui, hovered_row, time);
+        self.finish_removals(time);
+
+        if viewed && self.sort_mode == Some(SortMode::RecentlyViewed) {
+            self.sort(SortMode::RecentlyViewed, time);
+            // The clicked row is now the most recent one
+            self.clicked_row = self.clicked_row.map(|_| 0);
+            needs_redraw = true;
+        }
+
+        if self.paused_at.is_some() {
+            // Regaining focus repaints on its own
+        } else if needs_redraw {
+            ui.ctx().request_repaint();
+        } else if self.play_once && !self.played && revealed == self.rows.len() {
+            self.played = true;
+            ui.data_mut(|d| d.insert_temp(played_id, true));
+        }
+    }
+}
+
+// Falls back to another family when the default one would draw placeholder boxes
+fn row_font(ui: &egui::Ui, text: &str, fallback: &Option<egui::FontFamily>) -> egui::FontId {
+    let font = egui::FontId::new(20.0, egui::FontFamily::Proportional);
+    let covers = |font: &egui::FontId| ui.fonts(|fonts| fonts.has_glyphs(font, text));
+    // Only worth switching when the fallback has the glyphs the default font lacks
+    match fallback {
+        Some(family) if !covers(&font) => {
+            let fallback = egui::FontId::new(20.0, family.clone());
+            if covers(&fallback) { fallback } else { font }
+        }
+        _ => font,
+    }
+}
+
+// Narrows a response for the whole row down to one cell, by where the pointer is
+fn cell_response(row_response: &egui::Response, cell: Rect, sense: egui::Sense) -> egui::Response {
+    use egui::response::Flags;
+    let mut response = row_response.clone();
+    response.rect = cell;
+    response.interact_rect = cell;
+    response.sense = sense;
+    let pointer = row_response
+        .interact_pointer_pos()
+        .or_else(|| row_response.hover_pos());
+    if !pointer.is_some_and(|pos| cell.contains(pos)) {
+        response.flags &= Flags::ENABLED;
+    }
+    // The row may sense more than the cell, e.g. clicks for the background
+    if !sense.senses_click() {
+        response.flags -= Flags::CLICKED | Flags::FAKE_PRIMARY_CLICKED | Flags::LONG_TOUCHED;
+    }
+    if !sense.senses_drag() {
+        response.flags -= Flags::DRAG_STARTED | Flags::DRAGGED | Flags::DRAG_STOPPED;
+    }
+    if !sense.interactive() {
+        response.flags -= Flags::IS_POINTER_BUTTON_DOWN_ON;
+    }
+    response
+}
+
+fn cell_fill(
+    response: &egui::Response,
+    fill: Color32,
+    hover_fill: Option<Color32>,
+    pressed_fill: Option<Color32>,
+) -> Color32 {
+    if response.is_pointer_button_down_on() {
+        pressed_fill.or(hover_fill).unwrap_or(fill)
+    } else if response.hovered() {
+        hover_fill.unwrap_or(fill)
+    } else {
+        fill
+    }
+}
+
+// Without feathering the rect is tessellated here, since the context options apply to every shape
+fn row_rect_shape(ui: &egui::Ui, rect: Rect, fill: Color32, feathering: bool) -> egui::Shape {
+    if feathering {
+        return egui::Shape::rect_filled(rect, 0.0, fill);
+    }
+
+    let options = egui::epaint::TessellationOptions {
+        feathering: false,
+        ..ui.ctx().tessellation_options(|o| *o)
+    };
+    let mut tessellator =
+        egui::epaint::Tessellator::new(ui.ctx().pixels_per_point(), options, [1, 1], vec![]);
+    let mut mesh = egui::Mesh::default();
+    tessellator.tessellate_rect(&egui::epaint::RectShape::filled(rect, 0.0, fill), &mut mesh);
+    egui::Shape::mesh(mesh)
+}
+
+// Square plot of a cubic-bezier easing curve with draggable control points
+#[derive(Default)]
+struct BezierEasingEditor {
+    curve: CubicBezier,
+}
+
+impl BezierEasingEditor {
+    const SIZE: f32 = 200.0;
+    const HANDLE_RADIUS: f32 = 6.0;
+
+    pub fn easing(&self) -> impl Fn(f32) -> f32 + 'static {
+        let curve = self.curve;
+        move |t| curve.ease(t)
+    }
+
+    // Returns true once a control point has been dragged and released
+    pub fn show(&mut self, ui: &mut egui::Ui) -> bool {
+        let (rect, _) = ui.allocate_exact_size(Vec2::splat(Self::SIZE), egui::Sense::hover());
+        let to_screen = |p: Vec2| {
+            Pos2::new(
+                rect.left() + p.x * rect.width(),
+                rect.bottom() - p.y * rect.height(),
+            )
+        };
+
+        let visuals = ui.visuals().widgets.inactive;
+        let mut changed = false;
+        for (i, point) in [&mut self.curve.p1, &mut self.curve.p2]
+            .into_iter()
+            .enumerate()
+        {
+            let handle_rect =
+                Rect::from_center_size(to_screen(*point), Vec2::splat(Self::HANDLE_RADIUS * 2.0));
+            let response = ui.interact(
+                handle_rect,
+                ui.id().with(("bezier_handle", i)),
+                egui::Sense::drag(),
+            );
+            if response.dragged() {
+                let delta = response.drag_delta() / rect.size();
+                *point = Vec2::new(
+                    (point.x + delta.x).clamp(0.0, 1.0),
+                    (point.y - delta.y).clamp(0.0, 1.0),
+                );
+            }
+            changed |= response.drag_stopped();
+        }
+
+        let painter = ui.painter();
+        painter.rect_stroke(rect, 0.0, visuals.bg_stroke, egui::StrokeKind::Inside);
+
+        let points = (0..=32)
+            .map(|i| to_screen(self.curve.point(i as f32 / 32.0)))
+            .collect();
+        painter.add(egui::Shape::line(points, visuals.fg_stroke));
+
+        for (anchor, point) in [
+            (Vec2::ZERO, self.curve.p1),
+            (Vec2::splat(1.0), self.curve.p2),
+        ] {
+            painter.line_segment([to_screen(anchor), to_screen(point)], visuals.bg_stroke);
+            painter.circle_filled(
+                to_screen(point),
+                Self::HANDLE_RADIUS,
+                visuals.fg_stroke.color,
+            );
+        }
+
+        changed
+    }
+}
+
+#[derive(Default)]
+struct AnimationApp {
+    row_list: AnimatedRowList,
+    easing_editor: BezierEasingEditor,
+    filter: String,
+    // Set by the search shortcut, consumed when the search field is next drawn
+    request_search_focus: bool,
+    puffin_frames: PuffinFrames,
+}
+
+const SELECTED_VERSION_KEY: &str = "selected_version";
+
+const SEARCH_SHORTCUT: egui::KeyboardShortcut =
+    egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::F);
+
+impl AnimationApp {
+    pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
+        let easing_editor = BezierEasingEditor::default();
+        let mut rows = Vec::with_capacity(101);
+        for x in 0..=100 {
+            rows.push(RowData::new(
+                format!("GE-Proton9-{}", x),
+                format!("/some/path/{}", x),
+            ));
+        }
+        let mut row_list = AnimatedRowList::new(rows, cc.egui_ctx.input(|i| i.time), 1.0, 0.1)
+            .with_on_empty(|ui| {
+                ui.label("No versions found");
+            })
+            .with_easing(easing_editor.easing())
+            .with_filter_debounce(0.15)
+            .with_stack_below(400.0);
+
+        // Bring back the version picked last time and scroll it into view
+        if let Some(version) = cc
+            .storage
+            .and_then(|storage| storage.get_string(SELECTED_VERSION_KEY))
+            && let Some(index) = row_list.index_of_version(&version)
+        {
+            row_list.set_selected_versions([version.as_str()]);
+            row_list.scroll_to(index);
+        }
+
+        Self {
+            row_list,
+            easing_editor,
+            filter: String::new(),
+            request_search_focus: false,
+            puffin_frames: PuffinFrames::default(),
+        }
+    }
+
+    fn with_puffin_frame_cadence(mut self, every_nth: u32, min_interval: Duration) -> Self {
+        self.puffin_frames = PuffinFrames::new(every_nth, min_interval);
+        self
+    }
+}
+
+impl eframe::App for AnimationApp {
+    fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        let selected = self.row_list.selected_versions().next().unwrap_or_default();
+        storage.set_string(SELECTED_VERSION_KEY, selected.to_owned());
+    }
+
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        if self.puffin_frames.update(Instant::now()) {
+            puffin::GlobalProfiler::lock().new_frame();
+        }
+        puffin::profile_scope!("AnimationApp::update");
+        egui::SidePanel::left("easing_editor").show(ctx, |ui| {
+            ui.label("Entrance easing");
+            if self.easing_editor.show(ui) {
+                self.row_list.set_easing(self.easing_editor.easing());
+                self.row_list.restart(ctx.input(|i| i.time));
+            }
+        });
+
+        // "/" only focuses the search when nothing else is taking text input
+        let typing = ctx.wants_keyboard_input();
+        self.request_search_focus |= ctx.input_mut(|i| {
+            if i.consume_shortcut(&SEARCH_SHORTCUT) {
+                return true;
+            }
+            if typing || !i.consume_key(egui::Modifiers::NONE, egui::Key::Slash) {
+                return false;
+            }
+            i.events
+                .retain(|event| !matches!(event, egui::Event::Text(text) if text == "/"));
+            true
+        });
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let search = ui.add(egui::TextEdit::singleline(&mut self.filter).hint_text("Search"));
+            if std::mem::take(&mut self.request_search_focus) {
+                search.request_focus();
+            }
+            if search.changed() {
+                self.row_list
+                    .type_filter(&self.filter, ctx.input(|i| i.time));
+            }
+
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                self.row_list.show(ui);
+            });
+        });
+    }
+}
+
+// Marks a puffin frame only every `every_nth` update and at least `min_interval` apart,
+// so an app running uncapped doesn't flood the capture with frames
+struct PuffinFrames {
+    every_nth: u32,
+    min_interval: Duration,
+    updates: u32,
+    last_frame: Option<Instant>,
+}
+
+impl Default for PuffinFrames {
+    fn default() -> Self {
+        Self::new(1, Duration::ZERO)
+    }
+}
+
+impl PuffinFrames {
+    fn new(every_nth: u32, min_interval: Duration) -> Self {
+        Self {
+            every_nth: every_nth.max(1),
+            min_interval,
+            updates: 0,
+            last_frame: None,
+        }
+    }
+
+    // Returns whether this update should start a new puffin frame
+    fn update(&mut self, now: Instant) -> bool {
+        self.updates += 1;
+        if self.updates < self.every_nth {
+            return false;
+        }
+        if self
+            .last_frame
+            .is_some_and(|last| now - last < self.min_interval)
+        {
+            return false;
+        }
+        self.updates = 0;
+        self.last_frame = Some(now);
+        true
+    }
+}
+
+fn start_puffin_server() {
+    puffin::set_scopes_on(true);
+    if let Ok(puffin_server) = puffin_http::Server::new("127.0.0.1:8585") {
+        std::process::Command::new("puffin_viewer")
+            .arg("--url")
+            .arg("127.0.0.1:8585")
+            .spawn()
+            .ok();
+        std::mem::forget(puffin_server);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[derive(Default)]
+    struct MemoryStorage(std::collections::HashMap<String, String>);
+
+    impl eframe::Storage for MemoryStorage {
+        fn get_string(&self, key: &str) -> Option<String> {
+            self.0.get(key).cloned()
+        }
+
+        fn set_string(&mut self, key: &str, value: String) {
+            self.0.insert(key.to_owned(), value);
+        }
+
+        fn flush(&mut self) {}
+    }
+
+    fn rows(count: usize) -> Vec<RowData> {
+        (0..count)
+            .map(|i| RowData::new(format!("GE-Proton9-{i}"), format!("/some/path/{i}")))
+            .collect()
+    }
+
+    fn input(time: f64, events: Vec<egui::Event>) -> egui::RawInput {
+        egui::RawInput {
+            time: Some(time),
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, Vec2::new(800.0, 600.0))),
+            events,
+            ..Default::default()
+        }
+    }
+
+    // Runs one headless frame with `add_contents` inside a central panel
+    fn run(
+        ctx: &egui::Context,
+        input: egui::RawInput,
+        mut add_contents: impl FnMut(&mut egui::Ui),
+    ) -> egui::FullOutput {
+        ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| add_contents(ui));
+        })
+    }
+
+    fn show(ctx: &egui::Context, list: &mut AnimatedRowList, time: f64) -> egui::FullOutput {
+        run(ctx, input(time, vec![]), |ui| list.show(ui))
+    }
+
+    fn pointer_button(pos: Pos2, pressed: bool) -> egui::Event {
+        egui::Event::PointerButton {
+            pos,
+            button: egui::PointerButton::Primary,
+            pressed,
+            modifiers: egui::Modifiers::NONE,
+        }
+    }
+
+    // Hovers, presses and releases at `pos` over three frames, as egui needs the last frame's widgets
+    fn click(ctx: &egui::Context, list: &mut AnimatedRowList, time: f64, pos: Pos2) {
+        let events = [
+            egui::Event::PointerMoved(pos),
+            pointer_button(pos, true),
+            pointer_button(pos, false),
+        ];
+        for (i, event) in events.into_iter().enumerate() {
+            run(ctx, input(time + i as f64 * 0.01, vec![event]), |ui| {
+                list.show(ui)
+            });
+        }
+    }
+
+    fn key(key: egui::Key, modifiers: egui::Modifiers) -> egui::Event {
+        egui::Event::Key {
+            key,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers,
+        }
+    }
+
+    fn update_app(
+        ctx: &egui::Context,
+        app: &mut AnimationApp,
+        input: egui::RawInput,
+    ) -> egui::FullOutput {
+        use eframe::App as _;
+        ctx.run(input, |ctx| {
+            app.update(ctx, &mut eframe::Frame::_new_kittest())
+        })
+    }
+
+    // Shows the list in a ScrollArea, optionally scrolled to `offset`, and returns the offset the
+    // frame started with since scroll deltas only apply at the end of the frame
+    fn scrolled(
+        ctx: &egui::Context,
+        list: &mut AnimatedRowList,
+        time: f64,
+        offset: Option<f32>,
+    ) -> f32 {
+        let mut started_at = 0.0;
+        run(ctx, input(time, vec![]), |ui| {
+            let mut area = egui::ScrollArea::vertical();
+            if let Some(offset) = offset {
+                area = area.vertical_scroll_offset(offset);
+            }
+            started_at = area.show(ui, |ui| list.show(ui)).state.offset.y;
+        });
+        started_at
+    }
+
+    // Everything the last pass registered to take input, in the order it was interacted
+    fn interactions(ctx: &egui::Context) -> Vec<egui::WidgetRect> {
+        ctx.viewport(|viewport| {
+            viewport
+                .prev_pass
+                .widgets
+                .layers()
+                .flat_map(|(_, widgets)| widgets)
+                .filter(|widget| widget.sense.interactive())
+                .copied()
+                .collect()
+        })
+    }
+
+    fn shapes(output: &egui::FullOutput) -> Vec<egui::Shape> {
+        fn flatten(shape: &egui::Shape, out: &mut Vec<egui::Shape>) {
+            match shape {
+                egui::Shape::Vec(shapes) => shapes.iter().for_each(|shape| flatten(shape, out)),
+                shape => out.push(shape.clone()),
+            }
+        }
+        let mut out = Vec::new();
+        for clipped in &output.shapes {
+            flatten(&clipped.shape, &mut out);
+        }
+        out
+    }
+
+    fn strokes(output: &egui::FullOutput) -> Vec<egui::Stroke> {
+        shapes(output)
+            .into_iter()
+            .filter_map(|shape| match shape {
+                egui::Shape::Rect(rect) if rect.stroke.width > 0.0 => Some(rect.stroke),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn fill_at(output: &egui::FullOutput, pos: Pos2) -> Color32 {
+        shapes(output)
+            .into_iter()
+            .filter_map(|shape| match shape {
+                egui::Shape::Rect(rect) if rect.rect.contains(pos) => Some(rect.fill),
+                _ => None,
+            })
+            .filter(|fill| *fill != Color32::TRANSPARENT)
+            .last()
+            .expect("nothing was filled there")
+    }
+
+    fn texts(output: &egui::FullOutput) -> Vec<egui::epaint::TextShape> {
+        shapes(output)
+            .into_iter()
+            .filter_map(|shape| match shape {
+                egui::Shape::Text(text) => Some(text),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn text_pos(output: &egui::FullOutput, text: &str) -> Pos2 {
+        texts(output)
+            .iter()
+            .find(|shape| shape.galley.text() == text)
+            .map(|shape| shape.pos)
+            .expect("text was not painted")
+    }
+
+    // Alpha of the first text drawn with exactly `text`
+    fn text_alpha(output: &egui::FullOutput, text: &str) -> u8 {
+        texts(output)
+            .iter()
+            .find(|shape| shape.galley.text() == text)
+            .and_then(|shape| shape.override_text_color)
+            .map(|color| color.a())
+            .expect("text was not painted")
+    }
+
+    #[test]
+    fn empty_list_calls_on_empty() {
+        let ctx = egui::Context::default();
+        let called = Rc::new(Cell::new(0));
+        let on_empty = called.clone();
+        let mut list = AnimatedRowList::new(vec![], 0.0, 1.0, 0.1)
+            .with_on_empty(move |_| on_empty.set(on_empty.get() + 1));
+        show(&ctx, &mut list, 0.0);
+        assert_eq!(called.get(), 1);
+
+        // A filter hiding every row counts as empty too
+        let on_empty = called.clone();
+        let mut list = AnimatedRowList::new(rows(3), 0.0, 1.0, 0.1)
+            .with_on_empty(move |_| on_empty.set(on_empty.get() + 1));
+        show(&ctx, &mut list, 0.0);
+        assert_eq!(called.get(), 1);
+        list.set_filter("no such version");
+        show(&ctx, &mut list, 0.1);
+        assert_eq!(called.get(), 2);
+    }
+
+    #[test]
+    fn play_once_does_not_replay() {
+        let ctx = egui::Context::default();
+        let mut list = AnimatedRowList::new(rows(3), 0.0, 1.0, 0.1).with_play_once(true);
+        for time in [0.0, 0.5, 2.0] {
+            show(&ctx, &mut list, time);
+        }
+        assert!(list.played);
+
+        // Rebuilt in the same place it starts settled, while a new list next to it still plays
+        let mut rebuilt = AnimatedRowList::new(rows(3), 3.0, 1.0, 0.1).with_play_once(true);
+        let mut other = AnimatedRowList::new(
+            vec![RowData::new("other".into(), "/other".into())],
+            3.0,
+            1.0,
+            0.1,
+        )
+        .with_play_once(true);
+        let output = run(&ctx, input(3.0, vec![]), |ui| {
+            rebuilt.show(ui);
+            other.show(ui);
+        });
+        assert_eq!(text_alpha(&output, "GE-Proton9-0"), 255);
+        assert_eq!(text_alpha(&output, "other"), 0);
+    }
+
+    #[test]
+    fn equal_versions_sort_by_path() {
+        let rows = [
+            ("GE-Proton9-10", "/c"),
+            ("GE-Proton9-1", "/b"),
+            ("GE-Proton9-9", "/d"),
+            ("GE-Proton9-1", "/a"),
+        ]
+        .map(|(version, path)| RowData::new(version.into(), path.into()));
+        let mut list = AnimatedRowList::new(rows.into(), 0.0, 1.0, 0.1);
+        list.sort_by_version();
+        let order: Vec<(&str, &str)> = list
+            .rows
+            .iter()
+            .map(|row| (row.data.version.as_str(), row.data.path.as_str()))
+            .collect();
+        assert_eq!(
+            order,
+            [
+                ("GE-Proton9-1", "/a"),
+                ("GE-Proton9-1", "/b"),
+                ("GE-Proton9-9", "/d"),
+                ("GE-Proton9-10", "/c"),
+            ]
+        );
+    }
+
+    #[test]
+    fn without_feathering_rows_are_tessellated_here() {
+        let ctx = egui::Context::default();
+        let meshes = |output: &egui::FullOutput| -> Vec<Arc<egui::Mesh>> {
+            shapes(output)
+                .into_iter()
+                .filter_map(|shape| match shape {
+                    egui::Shape::Mesh(mesh) => Some(mesh),
+                    _ => None,
+                })
+                .collect()
+        };
+
+        let mut list = AnimatedRowList::new(rows(3), 0.0, 1.0, 0.1);
+        assert!(meshes(&show(&ctx, &mut list, 5.0)).is_empty());
+
+        let mut list = AnimatedRowList::new(rows(3), 0.0, 1.0, 0.1).with_feathering(false);
+        let meshes = meshes(&show(&ctx, &mut list, 5.0));
+        assert_eq!(meshes.len(), 6);
+        // A feathered rect would add an outer ring of transparent vertices
+        assert!(meshes.iter().all(|mesh| mesh.vertices.len() == 4));
+    }
+
+    #[test]
+    fn show_records_its_cost() {
+        let ctx = egui::Context::default();
+        let mut list = AnimatedRowList::new(rows(20), 0.0, 1.0, 0.1);
+        assert_eq!(list.last_frame_cost(), Duration::ZERO);
+        show(&ctx, &mut list, 0.5);
+        assert!(list.last_frame_cost() > Duration::ZERO);
+    }
+
+    #[test]
+    fn entering_rows_ignore_clicks() {
+        let ctx = egui::Context::default();
+        // Early in a slow entrance the version cell still covers the right half of the row
+        let pos = Pos2::new(600.0, 30.0);
+        let mut list = AnimatedRowList::new(rows(1), 0.0, 10.0, 0.0);
+        click(&ctx, &mut list, 0.1, pos);
+        assert_eq!(list.clicked_row(), None);
+        assert!(matches!(list.rows[0].data.editing, Editing::NONE));
+
+        let mut list =
+            AnimatedRowList::new(rows(1), 0.0, 10.0, 0.0).with_interactive_while_animating(true);
+        click(&egui::Context::default(), &mut list, 0.1, pos);
+        assert_eq!(list.clicked_row(), Some(0));
+        assert!(matches!(list.rows[0].data.editing, Editing::VERSION));
+    }
+
+    #[test]
+    fn color_animation_passes_through_intermediate_values() {
+        assert_eq!(Color32::BLACK.lerp(Color32::WHITE, 0.0), Color32::BLACK);
+        assert_eq!(
+            Color32::BLACK.lerp(Color32::WHITE, 0.5),
+            Color32::from_gray(128)
+        );
+        assert_eq!(Color32::BLACK.lerp(Color32::WHITE, 1.0), Color32::WHITE);
+
+        let mut fill = PropertyAnimation::new(Color32::from_rgb(0, 0, 200)).with_easing(|t| t);
+        fill.animate_to(Color32::from_rgb(200, 100, 0), 1.0, 2.0);
+        assert_eq!(fill.value(1.0), Color32::from_rgb(0, 0, 200));
+        assert_eq!(fill.value(1.5), Color32::from_rgb(50, 25, 150));
+        assert_eq!(fill.value(2.0), Color32::from_rgb(100, 50, 100));
+        assert_eq!(fill.value(3.0), Color32::from_rgb(200, 100, 0));
+    }
+
+    #[test]
+    fn first_visible_index_follows_the_scroll_offset() {
+        let ctx = egui::Context::default();
+        let mut list = AnimatedRowList::new(rows(20), 0.0, 1.0, 0.1);
+        // Rows are 60 tall with 3 between them, so 130 down is inside the third row
+        let mut first = None;
+        for time in [5.0, 5.1] {
+            run(&ctx, input(time, vec![]), |ui| {
+                egui::ScrollArea::vertical()
+                    .vertical_scroll_offset(130.0)
+                    .show(ui, |ui| {
+                        list.show(ui);
+                        first = list.first_visible_index(ui);
+                    });
+            });
+        }
+        assert_eq!(first, Some(2));
+    }
+
+    #[test]
+    fn unfocused_window_freezes_the_entrance() {
+        let ctx = egui::Context::default();
+        let mut list = AnimatedRowList::new(rows(1), 0.0, 1.0, 0.0).with_pause_on_focus_loss(true);
+        let mut frame = |time: f64, focused: bool| {
+            let input = egui::RawInput {
+                focused,
+                ..input(time, vec![])
+            };
+            text_alpha(&run(&ctx, input, |ui| list.show(ui)), "GE-Proton9-0")
+        };
+        let before = frame(0.2, true);
+        assert_eq!(frame(0.3, false), frame(0.8, false));
+        // Picks up where it stopped once focused again
+        let resumed = frame(1.3, true);
+        assert!(resumed > before && resumed < 255);
+
+        // A marquee that was scrolling carries on from the same spot
+        let ctx = egui::Context::default();
+        let version = "GE-Proton9-0-with-a-very-long-build-suffix-attached";
+        let mut list = AnimatedRowList::new(
+            vec![RowData::new(version.into(), "/some/path/0".into())],
+            0.0,
+            1.0,
+            0.0,
+        )
+        .with_version_overflow(VersionOverflow::MarqueeOnHover)
+        .with_pause_on_focus_loss(true);
+        let mut frame = |time: f64, focused: bool, events: Vec<egui::Event>| {
+            let input = egui::RawInput {
+                focused,
+                ..input(time, events)
+            };
+            text_pos(&run(&ctx, input, |ui| list.show(ui)), version).x
+        };
+        let hover = vec![egui::Event::PointerMoved(Pos2::new(100.0, 30.0))];
+        frame(5.0, true, vec![]);
+        frame(5.1, true, hover);
+        let scrolled = frame(5.3, true, vec![]);
+        frame(5.4, false, vec![]);
+        frame(9.4, true, vec![]);
+        // 0.2s more of scrolling, not the 4s spent unfocused
+        assert!((frame(9.5, true, vec![]) - (scrolled - 0.2 * MARQUEE_SPEED)).abs() < 0.01);
+
+        // So does a scroll_to that was half way to its row
+        let ctx = egui::Context::default();
+        let mut list = AnimatedRowList::new(rows(20), 0.0, 1.0, 0.0)
+            .with_scroll_easing(|t| t)
+            .with_pause_on_focus_loss(true);
+        let frame = |list: &mut AnimatedRowList, time: f64, focused: bool| {
+            let mut started_at = 0.0;
+            let input = egui::RawInput {
+                focused,
+                ..input(time, vec![])
+            };
+            let _ = run(&ctx, input, |ui| {
+                started_at = egui::ScrollArea::vertical()
+                    .show(ui, |ui| list.show(ui))
+                    .state
+                    .offset
+                    .y;
+            });
+            started_at
+        };
+        frame(&mut list, 5.0, true);
+        list.scroll_to(10);
+        frame(&mut list, 5.0, true);
+        let half = 5.0 + SCROLL_DURATION as f64 / 2.0;
+        frame(&mut list, half, true);
+        frame(&mut list, half, false);
+        // Read a frame later, the reported offset is the one the frame started with
+        frame(&mut list, half + 4.0, true);
+        let offset = frame(&mut list, half + 4.0, true);
+        assert!((offset - 10.0 * 63.0 / 2.0).abs() < 0.5, "{offset}");
+
+        // A hover doesn't count the time spent unfocused towards its delay
+        let ctx = egui::Context::default();
+        let mut list = AnimatedRowList::new(rows(1), 0.0, 1.0, 0.0)
+            .with_hover_select_delay(0.5)
+            .with_pause_on_focus_loss(true);
+        let mut frame = |time: f64, focused: bool, events: Vec<egui::Event>| {
+            let input = egui::RawInput {
+                focused,
+                ..input(time, events)
+            };
+            let _ = run(&ctx, input, |ui| list.show(ui));
+            list.selected().collect::<Vec<_>>()
+        };
+        let hover = vec![egui::Event::PointerMoved(Pos2::new(100.0, 30.0))];
+        frame(5.0, true, vec![]);
+        frame(5.1, true, hover);
+        frame(5.3, false, vec![]);
+        assert_eq!(frame(9.3, true, vec![]), []);
+        assert_eq!(frame(9.6, true, vec![]), [0]);
+    }
+
+    #[test]
+    fn row_stroke_fades_in_with_the_row() {
+        let ctx = egui::Context::default();
+        let stroke = egui::Stroke::new(1.0, Color32::RED);
+        let mut list = AnimatedRowList::new(rows(1), 0.0, 1.0, 0.0)
+            .with_easing(|t| t)
+            .with_row_stroke(stroke);
+        let half = egui::Stroke::new(1.0, Color32::RED.gamma_multiply(0.5));
+        assert_eq!(strokes(&show(&ctx, &mut list, 0.5)), [half, half]);
+        assert_eq!(strokes(&show(&ctx, &mut list, 1.0)), [stroke, stroke]);
+    }
+
+    #[test]
+    fn growing_upward_puts_row_zero_at_the_bottom() {
+        let ctx = egui::Context::default();
+        let mut list = AnimatedRowList::new(rows(3), 0.0, 1.0, 0.1).with_grow_upward(true);
+        let output = show(&ctx, &mut list, 5.0);
+        let tops = [0, 1, 2].map(|i| text_pos(&output, &format!("GE-Proton9-{i}")).y);
+        assert!(tops[0] > tops[1] && tops[1] > tops[2]);
+        // Row 0's text sits within one row height of the panel's bottom edge
+        assert!(tops[0] > 600.0 - 60.0 - 8.0);
+    }
+
+    #[test]
+    fn sampled_easing_stays_close_to_the_curve() {
+        let easing = Easing::new(egui::emath::easing::cubic_in_out, DEFAULT_EASING_RESOLUTION);
+        for i in 0..=1000 {
+            let t = i as f32 / 1000.0;
+            assert!((easing.sample(t) - easing.exact(t)).abs() < 1e-3, "at {t}");
+        }
+        // A coarser table drifts further from the curve
+        let coarse = easing.with_resolution(4);
+        assert_eq!(coarse.resolution(), 4);
+        assert!((coarse.sample(0.25) - coarse.exact(0.25)).abs() > 1e-3);
+    }
+
+    #[test]
+    fn tooltip_only_for_disabled_rows_with_a_reason() {
+        let reason = "Not installed";
+        let hover_texts = |data: RowData| {
+            let ctx = egui::Context::default();
+            let mut list = AnimatedRowList::new(vec![data], 0.0, 1.0, 0.0);
+            let pos = Pos2::new(100.0, 30.0);
+            run(
+                &ctx,
+                input(5.0, vec![egui::Event::PointerMoved(pos)]),
+                |ui| list.show(ui),
+            );
+            // Tooltips wait for the pointer to rest first
+            let output = (1..=10)
+                .map(|i| show(&ctx, &mut list, 5.0 + i as f64 * 0.1))
+                .last()
+                .unwrap();
+            texts(&output)
+                .iter()
+                .map(|shape| shape.galley.text().to_owned())
+                .collect::<Vec<_>>()
+        };
+        let row = || RowData::new("GE-Proton9-0".into(), "/some/path/0".into());
+
+        assert!(hover_texts(row().disabled(Some(reason.into()))).contains(&reason.to_owned()));
+        assert!(!hover_texts(row().disabled(None)).contains(&reason.to_owned()));
+        let mut enabled = row();
+        enabled.disabled_reason = Some(reason.into());
+        assert!(!hover_texts(enabled).contains(&reason.to_owned()));
+    }
+
+    #[test]
+    fn default_bezier_matches_quadratic_out() {
+        let curve = CubicBezier::default();
+        for i in 0..=100 {
+            let t = i as f32 / 100.0;
+            let expected = egui::emath::easing::quadratic_out(t);
+            assert!((curve.ease(t) - expected).abs() < 1e-4, "at {t}");
+        }
+    }
+
+    #[test]
+    fn marquee_scrolls_only_while_hovered() {
+        let ctx = egui::Context::default();
+        let version = "GE-Proton9-0-with-a-very-long-build-suffix-attached";
+        let mut list = AnimatedRowList::new(
+            vec![RowData::new(version.into(), "/some/path/0".into())],
+            0.0,
+            1.0,
+            0.0,
+        )
+        .with_version_overflow(VersionOverflow::MarqueeOnHover);
+        let mut frame = |time: f64, events: Vec<egui::Event>| {
+            text_pos(&run(&ctx, input(time, events), |ui| list.show(ui)), version).x
+        };
+        let resting = frame(5.0, vec![]);
+        let hovered = frame(5.1, vec![egui::Event::PointerMoved(Pos2::new(100.0, 30.0))]);
+        let scrolled = frame(5.6, vec![]);
+        assert_eq!(hovered, resting);
+        assert!((hovered - scrolled - 0.5 * MARQUEE_SPEED).abs() < 0.01);
+
+        frame(
+            5.8,
+            vec![egui::Event::PointerMoved(Pos2::new(100.0, 500.0))],
+        );
+        assert_eq!(frame(5.9, vec![]), resting);
+    }
+
+    #[test]
+    fn content_height_sums_mixed_row_heights() {
+        let mut list = AnimatedRowList::new(rows(3), 0.0, 1.0, 0.1);
+        let spacing = egui::Spacing::default().item_spacing.y;
+        list.animate_row_height(1, 100.0, 0.0, 0.5);
+        // Uses the target height, even while the row is still growing
+        assert_eq!(list.content_height(), 60.0 + 100.0 + 60.0 + 2.0 * spacing);
+
+        list.set_filter("GE-Proton9-1");
+        assert_eq!(list.content_height(), 100.0);
+    }
+
+    #[test]
+    fn search_shortcuts_focus_the_search_field() {
+        let shortcuts = [
+            vec![key(egui::Key::F, egui::Modifiers::COMMAND)],
+            vec![
+                key(egui::Key::Slash, egui::Modifiers::NONE),
+                egui::Event::Text("/".into()),
+            ],
+        ];
+        for events in shortcuts {
+            let ctx = egui::Context::default();
+            let mut app = AnimationApp::new(&eframe::CreationContext::_new_kittest(ctx.clone()));
+            update_app(&ctx, &mut app, input(0.0, vec![]));
+            assert!(!ctx.wants_keyboard_input());
+            update_app(&ctx, &mut app, input(0.1, events));
+            update_app(&ctx, &mut app, input(0.2, vec![]));
+            assert!(ctx.wants_keyboard_input());
+            // The slash that opened the search isn't typed into it
+            assert!(app.filter.is_empty());
+        }
+    }
+
+    #[test]
+    fn pressed_fill_replaces_the_hover_fill() {
+        let ctx = egui::Context::default();
+        let mut list = AnimatedRowList::new(rows(1), 0.0, 1.0, 0.0)
+            .with_hover_fill(Color32::GREEN)
+            .with_pressed_fill(Color32::RED);
+        let pos = Pos2::new(100.0, 30.0);
+        let mut frame = |time: f64, events: Vec<egui::Event>| {
+            fill_at(&run(&ctx, input(time, events), |ui| list.show(ui)), pos)
+        };
+        assert_eq!(frame(5.0, vec![]), Color32::WHITE);
+        assert_eq!(
+            frame(5.1, vec![egui::Event::PointerMoved(pos)]),
+            Color32::GREEN
+        );
+        assert_eq!(frame(5.2, vec![pointer_button(pos, true)]), Color32::RED);
+        assert_eq!(frame(5.3, vec![pointer_button(pos, false)]), Color32::GREEN);
+    }
+
+    #[test]
+    fn select_click_toggles_selection_without_editing() {
+        let ctx = egui::Context::default();
+        let mut list =
+            AnimatedRowList::new(rows(2), 0.0, 1.0, 0.0).with_click_behavior(ClickBehavior::Select);
+        click(&ctx, &mut list, 5.0, Pos2::new(100.0, 30.0));
+        assert_eq!(list.selected().collect::<Vec<_>>(), [0]);
+        assert!(matches!(list.rows[0].data.editing, Editing::NONE));
+
+        click(&ctx, &mut list, 6.0, Pos2::new(100.0, 30.0));
+        assert_eq!(list.selected().count(), 0);
+    }
+
+    #[test]
+    fn long_staggers_are_clamped_to_the_entrance_limit() {
+        // 1s for the last row plus 18 half second delays is exactly the limit
+        let list = AnimatedRowList::new(rows(19), 0.0, 1.0, 0.5);
+        assert!(!list.stagger_clamped());
+        assert_eq!(list.total_entrance_time(), MAX_ENTRANCE_TIME);
+
+        let list = AnimatedRowList::new(rows(20), 0.0, 1.0, 0.5);
+        assert!(list.stagger_clamped());
+        assert!((list.total_entrance_time() - MAX_ENTRANCE_TIME).abs() < 1e-4);
+        assert!(list.rows[1].delay < 0.5);
+    }
+
+    #[test]
+    fn snapped_drag_lands_on_slot_tops() {
+        let ctx = egui::Context::default();
+        let mut list = AnimatedRowList::new(rows(5), 0.0, 1.0, 0.0)
+            .with_reorderable(true)
+            .with_snap_reorder(true);
+        let events = [
+            egui::Event::PointerMoved(Pos2::new(100.0, 30.0)),
+            pointer_button(Pos2::new(100.0, 30.0), true),
+            egui::Event::PointerMoved(Pos2::new(100.0, 60.0)),
+            // Past the middle of the second row but not the third
+            egui::Event::PointerMoved(Pos2::new(100.0, 140.0)),
+        ];
+        for (i, event) in events.into_iter().enumerate() {
+            run(&ctx, input(5.0 + i as f64 * 0.01, vec![event]), |ui| {
+                list.show(ui)
+            });
+        }
+        let drag = list.drag.as_ref().expect("the row is being dragged");
+        assert_eq!(drag.slot, 1);
+
+        // Mid-way between slots it is off a boundary, once the snap is over it sits on one
+        let snapped_at = 5.03;
+        let tops: Vec<f32> = list.slots.iter().map(|slot| slot.1).collect();
+        assert!(!tops.contains(&drag.slot_top.value(snapped_at + SNAP_DURATION as f64 / 2.0)));
+        assert_eq!(
+            drag.slot_top.value(snapped_at + SNAP_DURATION as f64),
+            tops[1]
+        );
+
+        let top = text_pos(&show(&ctx, &mut list, 5.5), "GE-Proton9-0").y;
+        assert_eq!(top, list.list_top + tops[1] + 60.0 * 0.3);
+    }
+
+    #[test]
+    fn small_drag_down_keeps_the_order() {
+        let ctx = egui::Context::default();
+        let mut list = AnimatedRowList::new(rows(5), 0.0, 1.0, 0.0).with_reorderable(true);
+        // Still within row 0, just past its middle
+        let events = [
+            egui::Event::PointerMoved(Pos2::new(100.0, 30.0)),
+            pointer_button(Pos2::new(100.0, 30.0), true),
+            egui::Event::PointerMoved(Pos2::new(100.0, 42.0)),
+            pointer_button(Pos2::new(100.0, 42.0), false),
+        ];
+        for (i, event) in events.into_iter().enumerate() {
+            run(&ctx, input(5.0 + i as f64 * 0.01, vec![event]), |ui| {
+                list.show(ui)
+            });
+        }
+        let versions: Vec<&str> = list
+            .rows
+            .iter()
+            .map(|row| row.data.version.as_str())
+            .collect();
+        assert_eq!(
+            versions,
+            (0..5)
+                .map(|i| format!("GE-Proton9-{i}"))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn capped_entrances_never_overlap_more_than_the_cap() {
+        let ctx = egui::Context::default();
+        let mut list =
+            AnimatedRowList::new(rows(10), 0.0, 1.0, 0.1).with_max_concurrent_entrances(2);
+        let check = |list: &mut AnimatedRowList, from: f64| {
+            for i in 0..=120 {
+                let time = from + i as f64 * 0.05;
+                show(&ctx, list, time);
+                let entering = list
+                    .rows
+                    .iter()
+                    .filter(|row| !row.hidden)
+                    .map(|row| row.get_progress(time, &list.easing, None))
+                    .filter(|progress| *progress > 0.0 && *progress < 1.0)
+                    .count();
+                assert!(entering <= 2, "{entering} rows entering at {time}");
+            }
+            assert!(list.rows.iter().all(|row| !row.queued));
+        };
+        check(&mut list, 0.0);
+
+        // Rows coming back from a filter queue up as well
+        list.set_filter("GE-Proton9-1");
+        show(&ctx, &mut list, 6.1);
+        list.set_filter("");
+        check(&mut list, 6.2);
+    }
+
+    #[test]
+    fn zero_width_ui_shows_nothing() {
+        let ctx = egui::Context::default();
+        let mut list = AnimatedRowList::new(rows(3), 0.0, 1.0, 0.1);
+        for time in [0.0, 0.5] {
+            let output = run(&ctx, input(time, vec![]), |ui| {
+                ui.allocate_ui(Vec2::new(0.0, 100.0), |ui| {
+                    ui.set_max_width(0.0);
+                    list.show(ui);
+                });
+            });
+            assert!(texts(&output).is_empty());
+        }
+    }
+
+    #[test]
+    fn recently_viewed_moves_the_clicked_row_to_the_top() {
+        let ctx = egui::Context::default();
+        let mut list = AnimatedRowList::new(rows(5), 0.0, 1.0, 0.0)
+            .with_sort_mode(SortMode::RecentlyViewed)
+            .with_click_behavior(ClickBehavior::Custom);
+        // Third row, below two 60 tall rows and their spacing
+        click(
+            &ctx,
+            &mut list,
+            5.0,
+            Pos2::new(100.0, 8.0 + 2.0 * 63.0 + 30.0),
+        );
+        assert_eq!(list.rows[0].data.version, "GE-Proton9-2");
+        assert_eq!(list.clicked_row(), Some(0));
+        let order: Vec<&str> = list
+            .rows
+            .iter()
+            .map(|row| row.data.version.as_str())
+            .collect();
+        assert_eq!(
+            order[1..],
+            [
+                "GE-Proton9-0",
+                "GE-Proton9-1",
+                "GE-Proton9-3",
+                "GE-Proton9-4"
+            ]
+        );
+    }
+
+    #[test]
+    fn galley_cache_stays_within_its_budget() {
+        let ctx = egui::Context::default();
+        let mut list = AnimatedRowList::new(rows(30), 0.0, 1.0, 0.0);
+        scrolled(&ctx, &mut list, 5.0, Some(0.0));
+        let visible = list.galley_memory();
+        let cached = |list: &AnimatedRowList| -> Vec<Option<Arc<egui::Galley>>> {
+            list.rows
+                .iter()
+                .map(|row| row.data.galley_version.clone())
+                .collect()
+        };
+        let before = cached(&list);
+
+        // Less than the view needs, the rows on screen still keep their galleys between frames
+        list.set_galley_memory_budget(visible / 2);
+        scrolled(&ctx, &mut list, 5.1, Some(0.0));
+        scrolled(&ctx, &mut list, 5.2, Some(0.0));
+        let after = cached(&list);
+        assert!(before.iter().zip(&after).all(|(a, b)| match (a, b) {
+            (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+            (a, b) => a.is_none() && b.is_none(),
+        }));
+
+        // Scrolled a page down, the rows that left the view are the ones dropped
+        let budget = visible * 3 / 2;
+        list.set_galley_memory_budget(budget);
+        scrolled(&ctx, &mut list, 5.3, Some(10.0 * 63.0));
+        scrolled(&ctx, &mut list, 5.4, Some(10.0 * 63.0));
+        assert!(list.galley_memory() <= budget);
+        assert!(list.rows[0].data.galley_version.is_none());
+        assert!(list.rows[15].data.galley_version.is_some());
+    }
+
+    #[test]
+    fn selected_focused_row_shows_fill_and_outline() {
+        let ctx = egui::Context::default();
+        let mut list = AnimatedRowList::new(rows(2), 0.0, 1.0, 0.0);
+        list.set_selected_versions(["GE-Proton9-0"]);
+        list.set_focused(Some(0));
+        show(&ctx, &mut list, 5.0);
+        let output = show(&ctx, &mut list, 5.5);
+        assert_eq!(
+            fill_at(&output, Pos2::new(100.0, 30.0)),
+            list.selection_fill
+        );
+        assert_eq!(strokes(&output), [list.focus_stroke]);
+    }
+
+    #[test]
+    fn background_click_focuses_without_clicking_a_cell() {
+        // The metadata column has no cell response, only the background under it
+        let pos = Pos2::new(700.0, 30.0);
+        let list = || {
+            AnimatedRowList::new(rows(2), 0.0, 1.0, 0.0)
+                .with_meta_column(0.2)
+                .with_row_background(Color32::GRAY)
+        };
+        let mut clicked = list();
+        click(&egui::Context::default(), &mut clicked, 5.0, pos);
+        assert_eq!(clicked.focused(), Some(0));
+        assert_eq!(clicked.clicked_row(), None);
+        assert!(matches!(clicked.rows[0].data.editing, Editing::NONE));
+
+        let mut passed_through = list().with_click_through_background(true);
+        click(&egui::Context::default(), &mut passed_through, 5.0, pos);
+        assert_eq!(passed_through.focused(), None);
+    }
+
+    #[test]
+    fn selection_fill_fades_in() {
+        let ctx = egui::Context::default();
+        let mut list = AnimatedRowList::new(rows(1), 0.0, 1.0, 0.0).with_selection_fade(0.2);
+        let pos = Pos2::new(100.0, 30.0);
+        show(&ctx, &mut list, 5.0);
+        list.set_selected_versions(["GE-Proton9-0"]);
+        // Red falls from the white fill towards the selection fill's 144
+        let reds: Vec<u8> = [5.0, 5.05, 5.1, 5.15, 5.3]
+            .map(|time| fill_at(&show(&ctx, &mut list, time), pos).r())
+            .into();
+        assert!(reds.windows(2).all(|pair| pair[0] > pair[1]), "{reds:?}");
+        assert_eq!(reds[4], list.selection_fill.r());
+    }
+
+    #[test]
+    fn arrow_keys_take_focus_from_hover() {
+        let ctx = egui::Context::default();
+        let mut list = AnimatedRowList::new(rows(4), 0.0, 1.0, 0.0).with_focus_follows_hover(true);
+        let second_row = Pos2::new(100.0, 8.0 + 63.0 + 30.0);
+        let mut frame = |time: f64, events: Vec<egui::Event>| {
+            run(&ctx, input(time, events), |ui| list.show(ui));
+            list.focused()
+        };
+        frame(5.0, vec![]);
+        assert_eq!(
+            frame(5.1, vec![egui::Event::PointerMoved(second_row)]),
+            Some(1)
+        );
+        assert_eq!(
+            frame(5.2, vec![key(egui::Key::ArrowDown, egui::Modifiers::NONE)]),
+            Some(2)
+        );
+        // The pointer resting on the second row doesn't take the focus back
+        assert_eq!(frame(5.3, vec![]), Some(2));
+        // Moving it does, once egui has enough samples to see the pointer moving
+        for i in 1..=3 {
+            let moved = second_row + Vec2::X * (i * 5) as f32;
+            frame(
+                5.4 + i as f64 * 0.03,
+                vec![egui::Event::PointerMoved(moved)],
+            );
+        }
+        assert_eq!(list.focused(), Some(1));
+    }
+
+    #[test]
+    fn selection_follows_the_row_through_a_sort() {
+        let mut list = AnimatedRowList::new(rows(5).into_iter().rev().collect(), 0.0, 1.0, 0.1);
+        list.set_selected_versions(["GE-Proton9-3"]);
+        assert_eq!(list.selected().collect::<Vec<_>>(), [1]);
+        list.sort_by_version();
+        assert_eq!(list.selected().collect::<Vec<_>>(), [3]);
+        assert_eq!(
+            list.selected_versions().collect::<Vec<_>>(),
+            ["GE-Proton9-3"]
+        );
+    }
+
+    #[test]
+    fn rows_replay_their_entrance_when_the_filter_clears() {
+        let ctx = egui::Context::default();
+        let mut list = AnimatedRowList::new(rows(3), 0.0, 1.0, 0.0);
+        list.set_filter("GE-Proton9-1");
+        show(&ctx, &mut list, 5.0);
+        list.set_filter("");
+        let output = show(&ctx, &mut list, 5.1);
+        assert!(text_alpha(&output, "GE-Proton9-0") < 255);
+        assert_eq!(text_alpha(&output, "GE-Proton9-1"), 255);
+        let output = show(&ctx, &mut list, 7.0);
+        assert_eq!(text_alpha(&output, "GE-Proton9-0"), 255);
+    }
+
+    #[test]
+    fn meta_column_draws_a_third_galley() {
+        let ctx = egui::Context::default();
+        let data = RowData::new("GE-Proton9-0".into(), "/some/path/0".into()).with_meta("1.2 GB");
+        let mut list = AnimatedRowList::new(vec![data], 0.0, 1.0, 0.0).with_meta_column(0.2);
+        let output = show(&ctx, &mut list, 5.0);
+        let texts: Vec<String> = texts(&output)
+            .iter()
+            .map(|shape| shape.galley.text().to_owned())
+            .collect();
+        assert_eq!(texts, ["GE-Proton9-0", "/some/path/0", "1.2 GB"]);
+        // Right of the path cell, which ends 80% of the way across
+        assert!(text_pos(&output, "1.2 GB").x > 8.0 + 784.0 * 0.8);
+    }
+
+    #[test]
+    fn scroll_to_follows_the_scroll_easing() {
+        let ctx = egui::Context::default();
+        let mut list = AnimatedRowList::new(rows(20), 0.0, 1.0, 0.0).with_scroll_easing(|t| t * t);
+        let frame = |list: &mut AnimatedRowList, time: f64| scrolled(&ctx, list, time, None);
+        frame(&mut list, 5.0);
+        list.scroll_to(10);
+        frame(&mut list, 5.0);
+        // The reported offset is the one the frame started with, so read it a frame later
+        let half = 5.0 + SCROLL_DURATION as f64 / 2.0;
+        frame(&mut list, half);
+        let offset = frame(&mut list, half);
+        // Row 10 is ten rows and their spacing down, half way there t * t gives a quarter of that
+        let target = 10.0 * 63.0;
+        assert!((offset - target / 4.0).abs() < 0.5, "{offset}");
+        frame(&mut list, 5.0 + SCROLL_DURATION as f64);
+        assert!((frame(&mut list, 6.0) - target).abs() < 0.5);
+    }
+
+    #[test]
+    fn streamed_rows_are_admitted_at_the_append_limit() {
+        let ctx = egui::Context::default();
+        let mut list = AnimatedRowList::new(vec![], 0.0, 1.0, 0.1).with_append_limit(50);
+        list.push_rows(rows(1000));
+        for frame in 1..=20 {
+            show(&ctx, &mut list, frame as f64 * 0.01);
+            assert_eq!(list.rows.len(), frame * 50);
+            assert_eq!(list.pending_rows(), 1000 - frame * 50);
+        }
+        show(&ctx, &mut list, 0.3);
+        assert_eq!(list.rows.len(), 1000);
+        assert_eq!(list.rows[999].data.version, "GE-Proton9-999");
+    }
+
+    #[test]
+    fn text_missing_from_the_default_font_uses_the_fallback() {
+        let ctx = egui::Context::default();
+        // Only the emoji font covers the version, no font shipped with egui has the CJK path
+        let fallback = egui::FontFamily::Name("emoji".into());
+        let mut fonts = egui::FontDefinitions::default();
+        fonts.families.insert(
+            egui::FontFamily::Proportional,
+            vec!["Ubuntu-Light".to_owned()],
+        );
+        fonts
+            .families
+            .insert(fallback.clone(), vec!["NotoEmoji-Regular".to_owned()]);
+        ctx.set_fonts(fonts);
+
+        let data = RowData::new("😀".into(), "/游戏/版本".into());
+        let mut list =
+            AnimatedRowList::new(vec![data], 0.0, 1.0, 0.0).with_fallback_font(fallback.clone());
+        let _ = show(&ctx, &mut list, 5.0);
+        let family = |galley: &Option<Arc<egui::Galley>>| {
+            galley.as_ref().unwrap().job.sections[0]
+                .format
+                .font_id
+                .family
+                .clone()
+        };
+        assert_eq!(family(&list.rows[0].data.galley_version), fallback);
+        assert_eq!(
+            family(&list.rows[0].data.galley_path),
+            egui::FontFamily::Proportional
+        );
+    }
+
+    #[test]
+    fn changing_the_filter_jumps_back_to_the_top() {
+        let ctx = egui::Context::default();
+        let mut list = AnimatedRowList::new(rows(100), 0.0, 1.0, 0.0);
+        scrolled(&ctx, &mut list, 5.0, Some(300.0));
+        assert_eq!(scrolled(&ctx, &mut list, 5.1, None), 300.0);
+
+        // Still matches enough rows to scroll that far
+        list.set_filter("1");
+        scrolled(&ctx, &mut list, 5.2, None);
+        assert_eq!(scrolled(&ctx, &mut list, 5.3, None), 0.0);
+
+        let mut kept =
+            AnimatedRowList::new(rows(100), 0.0, 1.0, 0.0).with_reset_scroll_on_filter(false);
+        let ctx = egui::Context::default();
+        scrolled(&ctx, &mut kept, 5.0, Some(300.0));
+        kept.set_filter("1");
+        scrolled(&ctx, &mut kept, 5.2, None);
+        assert_eq!(scrolled(&ctx, &mut kept, 5.3, None), 300.0);
+    }
+
+    #[test]
+    fn badge_sits_at_the_right_edge() {
+        let ctx = egui::Context::default();
+        let data = RowData::new("GE-Proton9-0".into(), "/some/path/0".into()).with_badge("latest");
+        let mut list = AnimatedRowList::new(vec![data], 0.0, 1.0, 0.0);
+        let output = show(&ctx, &mut list, 5.0);
+        let badge = texts(&output)
+            .into_iter()
+            .find(|shape| shape.galley.text() == "latest")
+            .unwrap();
+        // Inset from the panel's right edge by 30% of the 60 tall row
+        let right = badge.pos.x + badge.galley.size().x;
+        assert!((right - (800.0 - 8.0 - 60.0 * 0.3)).abs() < 0.5, "{right}");
+        // And it doesn't slide in with the cells
+        let entering = show(&egui::Context::default(), &mut list, 0.0);
+        assert_eq!(text_pos(&entering, "latest"), badge.pos);
+    }
+
+    #[test]
+    fn rows_compact_while_scrolling() {
+        let ctx = egui::Context::default();
+        let mut list =
+            AnimatedRowList::new(rows(5), 0.0, 1.0, 0.0).with_compact_when_scrolling(0.5);
+        let wheel = egui::Event::MouseWheel {
+            unit: egui::MouseWheelUnit::Point,
+            delta: Vec2::new(0.0, -10.0),
+            modifiers: egui::Modifiers::NONE,
+        };
+        let mut frame = |time: f64, events: Vec<egui::Event>| {
+            run(&ctx, input(time, events), |ui| list.show(ui));
+            list.height_scale
+        };
+        frame(5.0, vec![egui::Event::PointerMoved(Pos2::new(100.0, 30.0))]);
+        assert!(frame(5.01, vec![wheel]) < 1.0);
+        let shrinking = frame(5.1, vec![]);
+        assert!(shrinking < 1.0 && shrinking > 0.5);
+        assert_eq!(frame(5.2, vec![]), 0.5);
+        // Held compact until the scrolling has stopped for a moment, then grows back
+        let growing = frame(5.3, vec![]);
+        assert!(growing > 0.5 && growing < 1.0);
+        assert_eq!(frame(5.6, vec![]), 1.0);
+    }
+
+    #[test]
+    fn overshooting_easing_keeps_colours_in_range() {
+        let list = |easing: fn(f32) -> f32| {
+            let mut list = AnimatedRowList::new(rows(1), 0.0, 1.0, 0.0)
+                .with_easing(easing)
+                .with_row_stroke(egui::Stroke::new(1.0, Color32::RED))
+                .with_row_background(Color32::GRAY);
+            list.set_focused(Some(0));
+            list
+        };
+
+        // Ends 20% past the target, still fully opaque rather than wrapping around
+        let mut overshoot = list(|t| t * 1.2);
+        let output = show(&egui::Context::default(), &mut overshoot, 1.0);
+        assert_eq!(text_alpha(&output, "GE-Proton9-0"), 255);
+        assert!(
+            strokes(&output)
+                .iter()
+                .all(|stroke| stroke.color.a() == 255)
+        );
+
+        // Dips below zero first, which must not reach gamma_multiply as a negative factor
+        let mut dip = list(egui::emath::easing::back_in);
+        let output = show(&egui::Context::default(), &mut dip, 0.2);
+        assert_eq!(text_alpha(&output, "GE-Proton9-0"), 0);
+        assert!(strokes(&output).iter().all(|stroke| stroke.color.a() == 0));
+    }
+
+    #[test]
+    fn debounced_typing_filters_once() {
+        let ctx = egui::Context::default();
+        let mut list = AnimatedRowList::new(rows(20), 0.0, 1.0, 0.0).with_filter_debounce(0.15);
+        let typed = "proton9-1";
+        for i in 1..=typed.len() {
+            let time = 5.0 + i as f64 * 0.05;
+            list.type_filter(&typed[..i], time);
+            show(&ctx, &mut list, time);
+        }
+        assert_eq!(list.filter_recomputes(), 0);
+        show(&ctx, &mut list, 6.0);
+        assert_eq!(list.filter_recomputes(), 1);
+        assert_eq!(list.rows.iter().filter(|row| !row.hidden).count(), 11);
+
+        // Without a debounce every keystroke filters the rows again
+        let mut list = AnimatedRowList::new(rows(20), 0.0, 1.0, 0.0);
+        for i in 1..=typed.len() {
+            list.type_filter(&typed[..i], 5.0);
+        }
+        assert_eq!(list.filter_recomputes(), typed.len());
+    }
+
+    #[test]
+    fn selected_version_survives_a_restart() {
+        use eframe::App as _;
+        let ctx = egui::Context::default();
+        let mut app = AnimationApp::new(&eframe::CreationContext::_new_kittest(ctx.clone()));
+        app.row_list.set_selected_versions(["GE-Proton9-42"]);
+        let mut storage = MemoryStorage::default();
+        app.save(&mut storage);
+        assert_eq!(
+            storage.0.get(SELECTED_VERSION_KEY).map(String::as_str),
+            Some("GE-Proton9-42")
+        );
+
+        let mut cc = eframe::CreationContext::_new_kittest(ctx);
+        cc.storage = Some(&storage);
+        let restored = AnimationApp::new(&cc);
+        assert_eq!(
+            restored.row_list.selected_versions().collect::<Vec<_>>(),
+            ["GE-Proton9-42"]
+        );
+        assert_eq!(restored.row_list.scroll_target, Some(42));
+    }
+
+    #[test]
+    fn narrow_lists_stack_the_path_under_the_version() {
+        let positions = |width: f32| {
+            let ctx = egui::Context::default();
+            let mut list = AnimatedRowList::new(rows(1), 0.0, 1.0, 0.0).with_stack_below(400.0);
+            let output = run(&ctx, input(5.0, vec![]), |ui| {
+                ui.allocate_ui(Vec2::new(width, 200.0), |ui| {
+                    ui.set_max_width(width);
+                    list.show(ui);
+                });
+            });
+            (
+                text_pos(&output, "GE-Proton9-0"),
+                text_pos(&output, "/some/path/0"),
+            )
+        };
+        let (version, path) = positions(300.0);
+        assert!(path.y > version.y && (path.x - version.x).abs() < 1.0);
+        let (version, path) = positions(600.0);
+        assert!(path.y == version.y && path.x > version.x);
+    }
+
+    #[test]
+    fn reveal_fraction_shows_that_share_of_the_rows() {
+        let ctx = egui::Context::default();
+        let mut list = AnimatedRowList::new(rows(10), 0.0, 1.0, 0.0);
+        list.reveal_fraction(0.5);
+        let shown = |output: &egui::FullOutput| {
+            (0..10)
+                .filter(|i| text_alpha(output, &format!("GE-Proton9-{i}")) > 0)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(shown(&show(&ctx, &mut list, 5.0)), [0, 1, 2, 3, 4]);
+
+        // The rest enter from when they are revealed, not from the list's start
+        list.reveal_fraction(1.0);
+        show(&ctx, &mut list, 6.0);
+        let output = show(&ctx, &mut list, 6.5);
+        assert_eq!(shown(&output).len(), 10);
+        assert!(text_alpha(&output, "GE-Proton9-9") < 255);
+    }
+
+    #[test]
+    fn long_hover_selects_and_brief_hover_does_not() {
+        let ctx = egui::Context::default();
+        let mut list = AnimatedRowList::new(rows(3), 0.0, 1.0, 0.0).with_hover_select_delay(0.5);
+        list.rows[2].data.selected = true;
+        let frame = |list: &mut AnimatedRowList, time: f64, events: Vec<egui::Event>| {
+            let _ = run(&ctx, input(time, events), |ui| list.show(ui));
+        };
+        let hover = |y: f32| vec![egui::Event::PointerMoved(Pos2::new(100.0, y))];
+        frame(&mut list, 5.0, vec![]);
+        frame(&mut list, 5.1, hover(30.0));
+        frame(&mut list, 5.3, vec![]);
+        assert_eq!(list.selected().collect::<Vec<_>>(), [2]);
+        frame(&mut list, 5.7, vec![]);
+        assert_eq!(list.selected().collect::<Vec<_>>(), [0]);
+
+        // Leaving row 1 before the delay is up selects nothing
+        frame(&mut list, 5.8, hover(93.0));
+        frame(&mut list, 6.0, hover(500.0));
+        frame(&mut list, 6.6, vec![]);
+        assert_eq!(list.selected().collect::<Vec<_>>(), [0]);
+    }
+
+    #[test]
+    fn collapsing_rows_fade_and_shrink_to_nothing() {
+        let ctx = egui::Context::default();
+        let stroke = egui::Stroke::new(1.0, Color32::RED);
+        let mut list = AnimatedRowList::new(rows(2), 0.0, 1.0, 0.0)
+            .with_removal_style(RemovalStyle::Collapse)
+            .with_row_stroke(stroke);
+        let _ = show(&ctx, &mut list, 5.0);
+        list.remove_row(0, 5.0);
+        let slot_height = |list: &AnimatedRowList| list.slots[0].2;
+
+        let half = egui::Stroke::new(1.0, Color32::RED.gamma_multiply(0.5));
+        let output = show(&ctx, &mut list, 5.0 + REMOVAL_DURATION as f64 / 2.0);
+        assert_eq!(strokes(&output)[..2], [half, half]);
+        assert!(slot_height(&list) > 0.0 && slot_height(&list) < 60.0);
+
+        // The last frame before it is dropped lays the row out with no height at all
+        let _ = show(&ctx, &mut list, 5.0 + REMOVAL_DURATION as f64);
+        assert_eq!(slot_height(&list), 0.0);
+        assert_eq!(list.rows.len(), 1);
+    }
+
+    #[test]
+    fn required_selection_keeps_the_last_selected_row() {
+        let ctx = egui::Context::default();
+        let mut list = AnimatedRowList::new(rows(2), 0.0, 1.0, 0.0)
+            .with_click_behavior(ClickBehavior::Select)
+            .with_require_selection(true);
+        click(&ctx, &mut list, 5.0, Pos2::new(100.0, 30.0));
+        assert_eq!(list.selected().collect::<Vec<_>>(), [0]);
+        click(&ctx, &mut list, 6.0, Pos2::new(100.0, 30.0));
+        assert_eq!(list.selected().collect::<Vec<_>>(), [0]);
+
+        // With a second row selected the first can be cleared again
+        click(&ctx, &mut list, 7.0, Pos2::new(100.0, 93.0));
+        click(&ctx, &mut list, 8.0, Pos2::new(100.0, 30.0));
+        assert_eq!(list.selected().collect::<Vec<_>>(), [1]);
+    }
+
+    #[test]
+    fn ping_pong_plays_every_other_cycle_backwards() {
+        let list = AnimatedRowList::new(rows(1), 0.0, 1.0, 0.0)
+            .with_easing(|t| t)
+            .with_looping(LoopMode::PingPong);
+        let progress = |time: f64| list.rows[0].get_progress(time, &list.easing, list.looping);
+        let cycle = |from: f64| [0.25, 0.5, 0.75].map(|t| progress(from + t));
+        assert!(cycle(0.0).is_sorted());
+        assert!(cycle(1.0).is_sorted_by(|a, b| a > b));
+        assert!(cycle(2.0).is_sorted());
+        assert!((progress(1.25) - 0.75).abs() < 1e-3);
+    }
+
+    #[test]
+    fn puffin_frames_marks_every_nth_update() {
+        let mut frames = PuffinFrames::new(3, Duration::ZERO);
+        let now = Instant::now();
+        let marked: Vec<bool> = (0..6).map(|_| frames.update(now)).collect();
+        assert_eq!(marked, [false, false, true, false, false, true]);
+    }
+
+    #[test]
+    fn puffin_frames_waits_out_the_min_interval() {
+        let mut frames = PuffinFrames::new(1, Duration::from_millis(16));
+        let start = Instant::now();
+        assert!(frames.update(start));
+        assert!(!frames.update(start + Duration::from_millis(10)));
+        // The interval runs from the last marked frame, not the last update
+        assert!(frames.update(start + Duration::from_millis(16)));
+        assert!(!frames.update(start + Duration::from_millis(20)));
+    }
+
+    #[test]
+    fn shake_swings_both_ways_and_settles() {
+        let mut list = AnimatedRowList::new(rows(1), 0.0, 1.0, 0.0);
+        list.shake(0, 5.0);
+        let row = &mut list.rows[0];
+        // 31 steps never land exactly on a zero crossing
+        let offsets: Vec<f32> = (1..31)
+            .map(|i| row.shake_offset(5.0 + i as f64 * SHAKE_DURATION as f64 / 31.0))
+            .collect();
+        let swings = offsets
+            .windows(2)
+            .filter(|w| w[0].signum() != w[1].signum())
+            .count();
+        assert_eq!(swings, 2 * SHAKE_CYCLES as usize - 1);
+        assert!(offsets.iter().all(|x| x.abs() <= SHAKE_AMPLITUDE));
+
+        assert_eq!(row.shake_offset(5.0 + SHAKE_DURATION as f64), 0.0);
+        assert!(row.shake_start.is_none());
+    }
+
+    #[test]
+    fn single_interaction_covers_the_row_background() {
+        let ctx = egui::Context::default();
+        let list = |single: bool| {
+            AnimatedRowList::new(rows(3), 0.0, 1.0, 0.0)
+                .with_meta_column(0.2)
+                .with_row_background(Color32::GRAY)
+                .with_single_interaction(single)
+        };
+        let row_rects: Vec<Rect> = (0..3)
+            .map(|i| {
+                Rect::from_min_size(
+                    Pos2::new(8.0, 8.0 + 63.0 * i as f32),
+                    Vec2::new(784.0, 60.0),
+                )
+            })
+            .collect();
+
+        let mut separate = list(false);
+        let _ = show(&ctx, &mut separate, 5.0);
+        // A background and two cells per row
+        assert_eq!(interactions(&ctx).len(), 3 * 3);
+
+        let mut single = list(true);
+        let _ = show(&ctx, &mut single, 5.0);
+        let registered = interactions(&ctx);
+        let rects: Vec<Rect> = registered.iter().map(|widget| widget.rect).collect();
+        assert_eq!(rects, row_rects);
+        assert!(registered.iter().all(|widget| widget.sense.senses_click()));
+
+        // The background still takes the clicks that miss the cells
+        click(&ctx, &mut single, 6.0, Pos2::new(700.0, 30.0));
+        assert_eq!(single.focused(), Some(0));
+        assert_eq!(single.clicked_row(), None);
+        click(&ctx, &mut single, 7.0, Pos2::new(100.0, 93.0));
+        assert_eq!(single.clicked_row(), Some(1));
+    }
+
+    #[test]
+    fn delete_removes_the_focused_row_unless_it_is_filtered_out() {
+        let ctx = egui::Context::default();
+        let mut list = AnimatedRowList::new(rows(3), 0.0, 1.0, 0.0).with_delete_keys(true);
+        let delete = || vec![key(egui::Key::Delete, egui::Modifiers::NONE)];
+        list.set_focused(Some(1));
+        let _ = run(&ctx, input(5.0, delete()), |ui| list.show(ui));
+        assert!(list.rows[1].exiting.is_some());
+
+        let mut list = AnimatedRowList::new(rows(3), 0.0, 1.0, 0.0).with_delete_keys(true);
+        list.set_focused(Some(2));
+        list.set_filter("GE-Proton9-0");
+        let _ = run(&ctx, input(6.0, delete()), |ui| list.show(ui));
+        assert!(list.rows[2].hidden);
+        assert!(list.rows[2].exiting.is_none());
+    }
+
+    #[test]
+    fn offset_list_starts_once_the_first_has_finished() {
+        let ctx = egui::Context::default();
+        let mut first = AnimatedRowList::new(rows(2), 0.0, 1.0, 0.5).with_easing(|t| t);
+        let renamed = rows(2)
+            .into_iter()
+            .map(|row| RowData::new(row.version.replace("GE", "Wine"), row.path));
+        let mut second = AnimatedRowList::new(renamed.collect(), 0.0, 1.0, 0.0)
+            .with_max_concurrent_entrances(1)
+            .with_start_offset(first.total_entrance_time());
+        let mut frame = |time: f64| {
+            let output = run(&ctx, input(time, vec![]), |ui| {
+                first.show(ui);
+                second.show(ui);
+            });
+            (
+                text_alpha(&output, "GE-Proton9-1"),
+                text_alpha(&output, "Wine-Proton9-0"),
+            )
+        };
+        let (first_alpha, second_alpha) = frame(1.4);
+        assert!(first_alpha < 255);
+        assert_eq!(second_alpha, 0);
+        // The second list's first row is let out of the queue at 1.6 and shows from the next frame
+        let (first_alpha, second_alpha) = frame(1.6);
+        assert_eq!(first_alpha, 255);
+        assert_eq!(second_alpha, 0);
+        assert!(frame(1.8).1 > 0);
+    }
+
+    #[test]
+    fn pausing_holds_back_the_start_offset() {
+        let ctx = egui::Context::default();
+        let mut list = AnimatedRowList::new(rows(1), 0.0, 1.0, 0.0)
+            .with_max_concurrent_entrances(1)
+            .with_start_offset(1.0)
+            .with_pause_on_focus_loss(true);
+        let mut frame = |time: f64, focused: bool| {
+            let input = egui::RawInput {
+                focused,
+                ..input(time, vec![])
+            };
+            text_alpha(&run(&ctx, input, |ui| list.show(ui)), "GE-Proton9-0")
+        };
+        frame(0.2, true);
+        frame(0.3, false);
+        // A five second pause, after which 0.3s of the offset is still to go
+        assert_eq!(frame(5.3, true), 0);
+        assert_eq!(frame(5.9, true), 0);
+        frame(6.1, true);
+        assert!(frame(6.3, true) > 0);
     }
 }
@@ -1,12 +1,14 @@
-use crossbeam_channel::unbounded;
+use crossbeam_channel::{Receiver, Sender, unbounded};
 use eframe::egui::{self, Color32, Pos2, Rect, Vec2};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 // START PREPROCESSOR PASTE
 
 #[derive(Default)]
 struct RowData {
     version: String,
+    #[allow(dead_code)] // Only the version is drawn by this widget
     path: String,
     text_galley: Option<Arc<egui::Galley>>, // Cached text layout
 }
@@ -79,6 +81,13 @@ impl AnimatedRowList {
         }
     }
 
+    // Moves the whole entrance to a new start time, keeping the stagger
+    pub fn restart(&mut self, start_time: f64) {
+        for row in &mut self.rows {
+            row.start_time = start_time;
+        }
+    }
+
     pub fn show(&mut self, ui: &mut egui::Ui) {
         let time = ui.input(|i| i.time);
         let mut needs_redraw = false;
@@ -98,7 +107,7 @@ impl AnimatedRowList {
                     Pos2::new(x_offset, rect.top()),
                     Vec2::new(rect.width(), rect.height()),
                 );
-                let alpha = (255.0 * progress) as u8;
+                let alpha = (255.0 * progress.clamp(0.0, 1.0)) as u8;
 
                 // Direct painting to avoid allocations
                 ui.painter().rect_filled(
@@ -133,79 +142,230 @@ impl AnimatedRowList {
     }
 }
 
+// Marks a puffin frame only every `every_nth` update and at least `min_interval` apart,
+// so an app running uncapped doesn't flood the capture with frames
+struct PuffinFrames {
+    every_nth: u32,
+    min_interval: Duration,
+    updates: u32,
+    last_frame: Option<Instant>,
+}
+
+impl Default for PuffinFrames {
+    fn default() -> Self {
+        Self::new(1, Duration::ZERO)
+    }
+}
+
+impl PuffinFrames {
+    fn new(every_nth: u32, min_interval: Duration) -> Self {
+        Self {
+            every_nth: every_nth.max(1),
+            min_interval,
+            updates: 0,
+            last_frame: None,
+        }
+    }
+
+    // Returns whether this update should start a new puffin frame
+    fn update(&mut self, now: Instant) -> bool {
+        self.updates += 1;
+        if self.updates < self.every_nth {
+            return false;
+        }
+        if self
+            .last_frame
+            .is_some_and(|last| now - last < self.min_interval)
+        {
+            return false;
+        }
+        self.updates = 0;
+        self.last_frame = Some(now);
+        true
+    }
+}
+
 // END PREPROCESSOR PASTE
 
 pub fn set_native_options() -> eframe::NativeOptions {
-    let mut options = eframe::NativeOptions::default();
-    options.centered = true;
-    options.vsync = true;
-    options.renderer = eframe::Renderer::Wgpu;
-    options
+    eframe::NativeOptions {
+        centered: true,
+        vsync: true,
+        renderer: eframe::Renderer::Wgpu,
+        ..Default::default()
+    }
 }
 
 enum RowState {
-    Fetching(Option<crossbeam_channel::Receiver<Vec<RowData>>>),
+    // The worker sends a fully built list, so it can be shown the frame it arrives.
+    // False until the fetch has been started
+    Fetching(bool),
+    // Crossfades from the loading message to the rows, which animate in underneath it
+    Transitioning {
+        row_list: AnimatedRowList,
+        started: f64,
+    },
     Displaying(AnimatedRowList),
 }
 
+// A finished fetch and the generation it was started as
+type FetchResult = (u64, AnimatedRowList);
+
+struct LoadingMessage {
+    text: String,
+    font: egui::FontId,
+    spinner: bool,
+}
+
+impl LoadingMessage {
+    fn show(&self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            if self.spinner {
+                ui.spinner();
+            }
+            ui.label(egui::RichText::new(&self.text).font(self.font.clone()));
+        });
+    }
+}
+
 struct App {
     state: RowState,
+    // Every fetch is tagged with a generation, results from older fetches are dropped
+    generation: u64,
+    results: (Sender<FetchResult>, Receiver<FetchResult>),
+    loading: LoadingMessage,
+    transition_duration: f32,
+    puffin_frames: PuffinFrames,
 }
 
 impl App {
     fn new(_cc: &eframe::CreationContext<'_>) -> Self {
         Self {
-            state: RowState::Fetching(None),
+            state: RowState::Fetching(false),
+            generation: 0,
+            results: unbounded(),
+            loading: LoadingMessage {
+                text: "Fetching rows".to_owned(),
+                font: egui::FontId::default(),
+                spinner: false,
+            },
+            transition_duration: 0.3,
+            puffin_frames: PuffinFrames::default(),
         }
     }
 
+    fn with_loading(mut self, text: impl Into<String>, show_spinner: bool) -> Self {
+        self.loading.text = text.into();
+        self.loading.spinner = show_spinner;
+        self
+    }
+
+    fn with_loading_font(mut self, font: egui::FontId) -> Self {
+        self.loading.font = font;
+        self
+    }
+
+    fn with_transition_duration(mut self, duration: f32) -> Self {
+        self.transition_duration = duration;
+        self
+    }
+
+    fn with_puffin_frame_cadence(mut self, every_nth: u32, min_interval: Duration) -> Self {
+        self.puffin_frames = PuffinFrames::new(every_nth, min_interval);
+        self
+    }
+
     fn start_fetch(&mut self) {
-        let (s, r) = unbounded();
+        self.generation += 1;
+        let generation = self.generation;
+        let sender = self.results.0.clone();
         std::thread::spawn(move || {
-            std::thread::sleep(std::time::Duration::from_secs(3));
+            std::thread::sleep(Duration::from_secs(3));
             let rows = (0..=100)
                 .map(|x| RowData::new(format!("GE-Proton-{}", x), format!("/some/path/{}", x)))
                 .collect();
-            s.send(rows).expect("Failed to send rows");
+            // Start time is set once the list reaches the UI thread
+            sender
+                .send((generation, AnimatedRowList::new(rows, 0.0, 1.0, 0.1)))
+                .expect("Failed to send rows");
         });
 
-        self.state = RowState::Fetching(Some(r));
+        self.state = RowState::Fetching(true);
+    }
+
+    // Only the list from the latest fetch is kept, whatever order the workers finish in
+    fn receive_latest(&self) -> Option<AnimatedRowList> {
+        self.results
+            .1
+            .try_iter()
+            .filter(|(generation, _)| *generation == self.generation)
+            .last()
+            .map(|(_, row_list)| row_list)
     }
 }
 
 impl eframe::App for App {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        puffin::GlobalProfiler::lock().new_frame();
+        if self.puffin_frames.update(Instant::now()) {
+            puffin::GlobalProfiler::lock().new_frame();
+        }
         puffin::profile_scope!("App::update");
 
+        let time = ctx.input(|i| i.time);
         match &mut self.state {
-            RowState::Fetching(receiver_opt) => {
-                if let Some(receiver) = receiver_opt {
-                    if let Ok(rows) = receiver.try_recv() {
-                        self.state = RowState::Displaying(AnimatedRowList::new(
-                            rows,
-                            ctx.input(|i| i.time),
-                            1.0,
-                            0.1,
-                        ));
+            RowState::Fetching(started) => {
+                if *started {
+                    if let Some(mut row_list) = self.receive_latest() {
+                        // The rows start animating as the loading message starts fading
+                        row_list.restart(time);
+                        self.state = if self.transition_duration > 0.0 {
+                            RowState::Transitioning {
+                                row_list,
+                                started: time,
+                            }
+                        } else {
+                            RowState::Displaying(row_list)
+                        };
                         ctx.request_repaint();
                     } else {
-                        ctx.request_repaint_after(std::time::Duration::from_millis(100));
+                        ctx.request_repaint_after(Duration::from_millis(100));
                     }
                 } else {
                     self.start_fetch();
                 }
                 egui::CentralPanel::default().show(ctx, |ui| {
-                    ui.label("Fetching rows");
+                    self.loading.show(ui);
                 });
             }
-            RowState::Displaying(row_list) => {
+            RowState::Transitioning { row_list, started } => {
+                let fade = ((time - *started) as f32 / self.transition_duration).min(1.0);
                 let mut refresh = false;
                 egui::CentralPanel::default().show(ctx, |ui| {
-                    egui::ScrollArea::vertical().show(ui, |ui| {
-                        refresh = ui.button("Refresh").clicked();
-                        row_list.show(ui);
+                    // Laid out like the displayed rows, so nothing jumps once the fade is over
+                    let rect = ui.max_rect();
+                    ui.scope(|ui| {
+                        ui.multiply_opacity(fade);
+                        refresh = show_rows(ui, row_list);
                     });
+                    ui.scope_builder(egui::UiBuilder::new().max_rect(rect), |ui| {
+                        ui.multiply_opacity(1.0 - fade);
+                        self.loading.show(ui);
+                    });
+                });
+                if refresh {
+                    self.start_fetch();
+                } else if fade >= 1.0 {
+                    let state = std::mem::replace(&mut self.state, RowState::Fetching(false));
+                    if let RowState::Transitioning { row_list, .. } = state {
+                        self.state = RowState::Displaying(row_list);
+                    }
+                }
+                ctx.request_repaint();
+            }
+            RowState::Displaying(row_list) => {
+                let mut refresh = false;
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    refresh = show_rows(ui, row_list);
                 });
                 if refresh {
                     self.start_fetch();
@@ -215,6 +375,17 @@ impl eframe::App for App {
     }
 }
 
+// Returns true when the rows should be fetched again
+fn show_rows(ui: &mut egui::Ui, row_list: &mut AnimatedRowList) -> bool {
+    egui::ScrollArea::vertical()
+        .show(ui, |ui| {
+            let refresh = ui.button("Refresh").clicked();
+            row_list.show(ui);
+            refresh
+        })
+        .inner
+}
+
 fn main() {
     start_puffin_server();
     let options = set_native_options();
@@ -222,7 +393,15 @@ fn main() {
     let result = eframe::run_native(
         "Threaded Widget",
         options,
-        Box::new(|cc| Ok(Box::new(App::new(cc)))),
+        Box::new(|cc| {
+            Ok(Box::new(
+                App::new(cc)
+                    .with_loading("Fetching rows", true)
+                    .with_loading_font(egui::FontId::proportional(20.0))
+                    .with_transition_duration(0.3)
+                    .with_puffin_frame_cadence(1, Duration::from_millis(16)),
+            ))
+        }),
     );
 
     match result {
@@ -247,3 +426,168 @@ fn start_puffin_server() {
         std::mem::forget(puffin_server);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(time: f64) -> egui::RawInput {
+        egui::RawInput {
+            time: Some(time),
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, Vec2::new(800.0, 600.0))),
+            ..Default::default()
+        }
+    }
+
+    fn shapes(output: &egui::FullOutput) -> Vec<egui::Shape> {
+        fn flatten(shape: &egui::Shape, out: &mut Vec<egui::Shape>) {
+            match shape {
+                egui::Shape::Vec(shapes) => shapes.iter().for_each(|shape| flatten(shape, out)),
+                shape => out.push(shape.clone()),
+            }
+        }
+        let mut out = Vec::new();
+        for clipped in &output.shapes {
+            flatten(&clipped.shape, &mut out);
+        }
+        out
+    }
+
+    fn texts(output: &egui::FullOutput) -> Vec<egui::epaint::TextShape> {
+        shapes(output)
+            .into_iter()
+            .filter_map(|shape| match shape {
+                egui::Shape::Text(text) => Some(text),
+                _ => None,
+            })
+            .collect()
+    }
+
+    // The spinner is the only line the loading screen draws
+    fn spinner_drawn(output: &egui::FullOutput) -> bool {
+        shapes(output)
+            .iter()
+            .any(|shape| matches!(shape, egui::Shape::Path(_)))
+    }
+
+    // Alpha of the first text drawn with exactly `text`, after any opacity was applied
+    fn text_alpha(output: &egui::FullOutput, text: &str) -> Option<u8> {
+        texts(output)
+            .iter()
+            .find(|shape| shape.galley.text() == text)
+            .map(|shape| {
+                shape
+                    .override_text_color
+                    .unwrap_or(shape.fallback_color)
+                    .a()
+            })
+    }
+
+    fn app(ctx: &egui::Context) -> App {
+        App::new(&eframe::CreationContext::_new_kittest(ctx.clone()))
+    }
+
+    fn update(ctx: &egui::Context, app: &mut App, time: f64) -> egui::FullOutput {
+        use eframe::App as _;
+        ctx.run(input(time), |ctx| {
+            app.update(ctx, &mut eframe::Frame::_new_kittest())
+        })
+    }
+
+    // Stands in for a worker thread finishing, so the tests don't wait on the real fetch
+    fn fetched(app: &mut App, generation: u64, versions: &[&str]) {
+        let rows = versions
+            .iter()
+            .map(|version| RowData::new(version.to_string(), String::new()))
+            .collect();
+        app.state = RowState::Fetching(true);
+        app.results
+            .0
+            .send((generation, AnimatedRowList::new(rows, 0.0, 1.0, 0.1)))
+            .unwrap();
+    }
+
+    #[test]
+    fn show_loading_renders_the_configured_text() {
+        let ctx = egui::Context::default();
+        let font = egui::FontId::monospace(24.0);
+        let mut loading = app(&ctx)
+            .with_loading("Loading versions", true)
+            .with_loading_font(font.clone());
+        // A fetch is running and nothing has come back yet
+        loading.state = RowState::Fetching(true);
+        let output = update(&ctx, &mut loading, 0.0);
+        let texts = texts(&output);
+        let texts: Vec<(&str, &egui::FontId)> = texts
+            .iter()
+            .map(|shape| {
+                let format = &shape.galley.job.sections[0].format;
+                (shape.galley.text(), &format.font_id)
+            })
+            .collect();
+        assert_eq!(texts, [("Loading versions", &font)]);
+        assert!(spinner_drawn(&output));
+
+        let mut plain = app(&ctx).with_loading("Loading versions", false);
+        plain.state = RowState::Fetching(true);
+        assert!(!spinner_drawn(&update(&ctx, &mut plain, 1.0)));
+    }
+
+    #[test]
+    fn loading_message_crossfades_into_the_rows() {
+        let ctx = egui::Context::default();
+        let mut app = app(&ctx).with_transition_duration(0.4);
+        app.generation = 1;
+        fetched(&mut app, 1, &["GE-Proton-0"]);
+
+        update(&ctx, &mut app, 1.0);
+        assert!(matches!(app.state, RowState::Transitioning { .. }));
+
+        // Half way through, both are drawn and the rows are already moving in
+        let output = update(&ctx, &mut app, 1.2);
+        assert!(matches!(app.state, RowState::Transitioning { .. }));
+        let loading = text_alpha(&output, "Fetching rows").unwrap();
+        let rows = text_alpha(&output, "GE-Proton-0").unwrap();
+        assert!(loading.abs_diff(128) <= 1, "{loading}");
+        assert!(rows > 0 && rows < 128, "{rows}");
+
+        update(&ctx, &mut app, 1.4);
+        assert!(matches!(app.state, RowState::Displaying(_)));
+        let output = update(&ctx, &mut app, 1.5);
+        assert_eq!(text_alpha(&output, "Fetching rows"), None);
+        assert!(text_alpha(&output, "GE-Proton-0").is_some());
+    }
+
+    #[test]
+    fn received_list_arrives_complete() {
+        let ctx = egui::Context::default();
+        let mut app = app(&ctx).with_transition_duration(0.0);
+        app.generation = 1;
+        let versions: Vec<String> = (0..=100).map(|x| format!("GE-Proton-{x}")).collect();
+        let versions: Vec<&str> = versions.iter().map(String::as_str).collect();
+        fetched(&mut app, 1, &versions);
+
+        update(&ctx, &mut app, 1.0);
+        let RowState::Displaying(row_list) = &app.state else {
+            panic!("the rows should be displayed as soon as they arrive");
+        };
+        assert_eq!(row_list.rows.len(), 101);
+        let output = update(&ctx, &mut app, 1.1);
+        assert!(text_alpha(&output, "GE-Proton-0").is_some());
+    }
+
+    #[test]
+    fn receive_latest_drops_older_generations() {
+        let ctx = egui::Context::default();
+        let mut app = app(&ctx);
+        app.generation = 2;
+        fetched(&mut app, 2, &["current"]);
+        fetched(&mut app, 1, &["stale"]);
+        let row_list = app.receive_latest().expect("the current fetch was sent");
+        assert_eq!(row_list.rows[0].data.version, "current");
+
+        // A fetch that was superseded finishing late is ignored
+        fetched(&mut app, 1, &["stale"]);
+        assert!(app.receive_latest().is_none());
+    }
+}